@@ -1,16 +1,7 @@
-use std::fs::File;
-use std::io::prelude::*;
-
 use pdf_shape::*;
-use quick_xml::de::from_str;
-
-fn main() {
-    let mut file = File::open("./examples/xml_sample/sample_1.xml").unwrap();
-
-    let mut contents = String::new();
-    file.read_to_string(&mut contents).unwrap();
 
-    let document: Document = from_str(&contents).unwrap();
+fn main() -> Result<(), Error> {
+    let document = Document::from_path("./examples/xml_sample/sample_1.xml")?;
     let tokens = document.get_fsm_tokens();
 
     println!("Tokens width : {:?} pt", tokens.width());
@@ -36,4 +27,20 @@ fn main() {
     if let Some(vertical_spacing_mode) = tokens.mode_vertical_spacing() {
         println!("Vertical spacing (mode) : {} pt", vertical_spacing_mode);
     }
+
+    // Lines detection
+    for line in document.get_lines() {
+        println!("Line ({} pt) : {}", line.width(), line);
+    }
+
+    // Paragraphs detection
+    for paragraph in document.get_paragraphs() {
+        println!(
+            "Paragraph ({} lines) :\n{}",
+            paragraph.lines.len(),
+            paragraph
+        );
+    }
+
+    Ok(())
 }
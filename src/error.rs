@@ -0,0 +1,139 @@
+//! This module contains the errors that can occur while loading or writing a document
+
+use std::fmt;
+use std::io;
+
+/// An error that can occur while loading or writing a document
+#[derive(Debug)]
+pub enum Error {
+    /// The document can't be read
+    Io(io::Error),
+    /// The document is not a well formed XML document
+    Xml(quick_xml::Error),
+    /// A required attribute of an element is missing
+    MissingAttribute {
+        element: &'static str,
+        attribute: &'static str,
+        /// The id of the element, if known
+        id: Option<String>,
+    },
+    /// A numeric attribute of an element can't be parsed
+    ParseNumber {
+        element: &'static str,
+        attribute: &'static str,
+        value: String,
+        /// The id of the element, if known
+        id: Option<String>,
+    },
+    /// An attribute refers to an element which doesn't exist (e.g. an unknown style)
+    UnresolvedReference {
+        element: &'static str,
+        attribute: &'static str,
+        reference: String,
+        /// The id of the element, if known
+        id: Option<String>,
+    },
+    /// The positions of the document are given in an unsupported unit
+    UnsupportedUnit(String),
+    /// The document doesn't match the expected structure
+    Deserialization(quick_xml::DeError),
+    /// The document can't be written as JSON
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "I/O error: {}", error),
+            Error::Xml(error) => write!(f, "XML syntax error: {}", error),
+            Error::MissingAttribute {
+                element,
+                attribute,
+                id,
+            } => write!(
+                f,
+                "missing attribute `{}` on {}{}",
+                attribute,
+                element,
+                Error::element_id(id)
+            ),
+            Error::ParseNumber {
+                element,
+                attribute,
+                value,
+                id,
+            } => write!(
+                f,
+                "invalid number `{}` for attribute `{}` on {}{}",
+                value,
+                attribute,
+                element,
+                Error::element_id(id)
+            ),
+            Error::UnresolvedReference {
+                element,
+                attribute,
+                reference,
+                id,
+            } => write!(
+                f,
+                "unresolved reference `{}` in attribute `{}` on {}{}",
+                reference,
+                attribute,
+                element,
+                Error::element_id(id)
+            ),
+            Error::UnsupportedUnit(unit) => write!(f, "unsupported measurement unit `{}`", unit),
+            Error::Deserialization(error) => write!(f, "deserialization error: {}", error),
+            Error::Json(error) => write!(f, "JSON error: {}", error),
+        }
+    }
+}
+
+impl Error {
+    fn element_id(id: &Option<String>) -> String {
+        match id {
+            Some(id) => format!(" (id `{}`)", id),
+            None => String::new(),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            Error::Xml(error) => Some(error),
+            Error::Deserialization(error) => Some(error),
+            Error::Json(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<quick_xml::Error> for Error {
+    fn from(error: quick_xml::Error) -> Self {
+        Error::Xml(error)
+    }
+}
+
+impl From<quick_xml::DeError> for Error {
+    fn from(error: quick_xml::DeError) -> Self {
+        match error {
+            quick_xml::DeError::Xml(error) => Error::Xml(error),
+            error => Error::Deserialization(error),
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::Json(error)
+    }
+}
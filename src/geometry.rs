@@ -0,0 +1,295 @@
+//! This module contains the geometry shared by all the objects of a document
+
+/// An axis-aligned rectangle, in points, whose origin is the top left corner of the page
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+}
+
+impl BoundingBox {
+    /// Creates a bounding box from its top left corner and its size
+    ///
+    /// Negative sizes are turned into positive ones, the top left corner being moved accordingly
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> BoundingBox {
+        BoundingBox::from_edges(x, y, x + width, y + height)
+    }
+
+    /// Creates a bounding box from the positions of its edges
+    pub fn from_edges(left: f32, top: f32, right: f32, bottom: f32) -> BoundingBox {
+        BoundingBox {
+            x: left.min(right),
+            y: top.min(bottom),
+            width: (right - left).abs(),
+            height: (bottom - top).abs(),
+        }
+    }
+
+    /// Returns the smallest bounding box enclosing all the given boxes
+    ///
+    /// ⚠️ This method returns `None` if no box is given
+    pub fn enclosing<I: IntoIterator<Item = BoundingBox>>(boxes: I) -> Option<BoundingBox> {
+        boxes.into_iter().fold(
+            None,
+            |union: Option<BoundingBox>, bounding_box| match union {
+                Some(union) => Some(union.union(&bounding_box)),
+                None => Some(bounding_box),
+            },
+        )
+    }
+
+    /// Returns the x position of the left edge
+    pub fn x(&self) -> f32 {
+        self.x
+    }
+
+    /// Returns the y position of the top edge
+    pub fn y(&self) -> f32 {
+        self.y
+    }
+
+    /// Returns the width of the box
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    /// Returns the height of the box
+    pub fn height(&self) -> f32 {
+        self.height
+    }
+
+    /// Returns the x position of the left edge
+    pub fn left(&self) -> f32 {
+        self.x
+    }
+
+    /// Returns the y position of the top edge
+    pub fn top(&self) -> f32 {
+        self.y
+    }
+
+    /// Returns the x position of the right edge
+    pub fn right(&self) -> f32 {
+        self.x + self.width
+    }
+
+    /// Returns the y position of the bottom edge
+    pub fn bottom(&self) -> f32 {
+        self.y + self.height
+    }
+
+    /// Returns the area of the box, in square points
+    pub fn area(&self) -> f32 {
+        self.width * self.height
+    }
+
+    /// Returns the centre of the box as `(x, y)`
+    pub fn center(&self) -> (f32, f32) {
+        (self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+
+    /// Returns the smallest bounding box enclosing both boxes
+    pub fn union(&self, other: &BoundingBox) -> BoundingBox {
+        BoundingBox::from_edges(
+            self.left().min(other.left()),
+            self.top().min(other.top()),
+            self.right().max(other.right()),
+            self.bottom().max(other.bottom()),
+        )
+    }
+
+    /// Returns the area covered by both boxes
+    ///
+    /// Boxes which only share an edge or a corner don't overlap. Boxes without area (zero width or height, e.g. a link area drawn as a line or an empty image) overlap every box they touch, their intersection having no area either.
+    ///
+    /// ⚠️ This method returns `None` if the boxes don't overlap
+    pub fn intersection(&self, other: &BoundingBox) -> Option<BoundingBox> {
+        let left = self.left().max(other.left());
+        let top = self.top().max(other.top());
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+
+        let overlapping = if self.area() > 0.0 && other.area() > 0.0 {
+            left < right && top < bottom
+        } else {
+            left <= right && top <= bottom
+        };
+
+        if overlapping {
+            Some(BoundingBox::from_edges(left, top, right, bottom))
+        } else {
+            None
+        }
+    }
+
+    /// Checks if the boxes overlap, see [`BoundingBox::intersection`]
+    pub fn overlaps(&self, other: &BoundingBox) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the area covered by both boxes, in square points
+    pub fn overlap_area(&self, other: &BoundingBox) -> f32 {
+        self.intersection(other)
+            .map_or(0.0, |intersection| intersection.area())
+    }
+
+    /// Returns the intersection over union of the boxes, between 0 (disjoint boxes) and 1 (same boxes)
+    pub fn iou(&self, other: &BoundingBox) -> f32 {
+        let overlap_area = self.overlap_area(other);
+        let union_area = self.area() + other.area() - overlap_area;
+
+        if union_area > 0.0 {
+            overlap_area / union_area
+        } else {
+            0.0
+        }
+    }
+
+    /// Checks if a box lies entirely inside this box
+    pub fn contains(&self, other: &BoundingBox) -> bool {
+        self.left() <= other.left()
+            && self.top() <= other.top()
+            && self.right() >= other.right()
+            && self.bottom() >= other.bottom()
+    }
+
+    /// Checks if a point lies inside the box
+    pub fn contains_point(&self, x: f32, y: f32) -> bool {
+        self.left() <= x && x <= self.right() && self.top() <= y && y <= self.bottom()
+    }
+
+    /// Returns the horizontal gap between the boxes, 0 if they overlap horizontally
+    pub fn horizontal_distance(&self, other: &BoundingBox) -> f32 {
+        (other.left() - self.right())
+            .max(self.left() - other.right())
+            .max(0.0)
+    }
+
+    /// Returns the vertical gap between the boxes, 0 if they overlap vertically
+    pub fn vertical_distance(&self, other: &BoundingBox) -> f32 {
+        (other.top() - self.bottom())
+            .max(self.top() - other.bottom())
+            .max(0.0)
+    }
+
+    /// Returns the shortest distance between the edges of the boxes, 0 if they overlap
+    pub fn distance(&self, other: &BoundingBox) -> f32 {
+        self.horizontal_distance(other)
+            .hypot(self.vertical_distance(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boxes_are_built_from_their_edges() {
+        let bounding_box = BoundingBox::new(10.0, 20.0, -5.0, 8.0);
+
+        assert_eq!(bounding_box, BoundingBox::from_edges(5.0, 20.0, 10.0, 28.0));
+        assert_eq!(bounding_box.right(), 10.0);
+        assert_eq!(bounding_box.bottom(), 28.0);
+        assert_eq!(bounding_box.area(), 40.0);
+        assert_eq!(bounding_box.center(), (7.5, 24.0));
+    }
+
+    #[test]
+    fn unions_enclose_both_boxes() {
+        let first = BoundingBox::new(0.0, 0.0, 10.0, 10.0);
+        let second = BoundingBox::new(20.0, 5.0, 10.0, 20.0);
+
+        assert_eq!(first.union(&second), BoundingBox::new(0.0, 0.0, 30.0, 25.0));
+        assert_eq!(first.union(&first), first);
+        assert_eq!(
+            BoundingBox::enclosing(vec![first, second]),
+            Some(first.union(&second))
+        );
+        assert_eq!(BoundingBox::enclosing(Vec::new()), None);
+    }
+
+    #[test]
+    fn intersections_are_the_area_covered_by_both_boxes() {
+        let first = BoundingBox::new(0.0, 0.0, 10.0, 10.0);
+
+        assert_eq!(
+            first.intersection(&BoundingBox::new(5.0, 2.0, 10.0, 4.0)),
+            Some(BoundingBox::new(5.0, 2.0, 5.0, 4.0))
+        );
+        assert_eq!(first.intersection(&first), Some(first));
+        assert_eq!(
+            first.intersection(&BoundingBox::new(20.0, 0.0, 10.0, 10.0)),
+            None
+        );
+
+        // Boxes sharing an edge or a corner
+        assert_eq!(
+            first.intersection(&BoundingBox::new(10.0, 0.0, 10.0, 10.0)),
+            None
+        );
+        assert_eq!(
+            first.intersection(&BoundingBox::new(10.0, 10.0, 10.0, 10.0)),
+            None
+        );
+        assert!(!first.overlaps(&BoundingBox::new(0.0, 10.0, 10.0, 10.0)));
+    }
+
+    #[test]
+    fn boxes_without_area_overlap_the_boxes_they_touch() {
+        let first = BoundingBox::new(0.0, 0.0, 10.0, 10.0);
+        let line = BoundingBox::new(2.0, 10.0, 6.0, 0.0);
+        let point = BoundingBox::new(5.0, 5.0, 0.0, 0.0);
+
+        assert_eq!(first.intersection(&line), Some(line));
+        assert_eq!(line.intersection(&first), Some(line));
+        assert_eq!(first.intersection(&point), Some(point));
+        assert_eq!(point.intersection(&point), Some(point));
+        assert_eq!(first.overlap_area(&line), 0.0);
+        assert!(!line.overlaps(&BoundingBox::new(2.0, 11.0, 6.0, 0.0)));
+    }
+
+    #[test]
+    fn iou_goes_from_disjoint_to_same_boxes() {
+        let first = BoundingBox::new(0.0, 0.0, 10.0, 10.0);
+
+        assert_eq!(first.iou(&first), 1.0);
+        assert_eq!(first.iou(&BoundingBox::new(20.0, 0.0, 10.0, 10.0)), 0.0);
+        assert_eq!(
+            first.iou(&BoundingBox::new(5.0, 0.0, 10.0, 10.0)),
+            50.0 / 150.0
+        );
+        assert_eq!(first.iou(&BoundingBox::new(0.0, 0.0, 5.0, 10.0)), 0.5);
+
+        let point = BoundingBox::new(5.0, 5.0, 0.0, 0.0);
+        assert_eq!(point.iou(&point), 0.0);
+    }
+
+    #[test]
+    fn boxes_contain_the_boxes_inside_them() {
+        let first = BoundingBox::new(0.0, 0.0, 10.0, 10.0);
+
+        assert!(first.contains(&first));
+        assert!(first.contains(&BoundingBox::new(2.0, 2.0, 8.0, 0.0)));
+        assert!(!first.contains(&BoundingBox::new(5.0, 5.0, 10.0, 2.0)));
+        assert!(!BoundingBox::new(2.0, 2.0, 2.0, 2.0).contains(&first));
+        assert!(first.contains_point(10.0, 0.0));
+        assert!(!first.contains_point(10.5, 0.0));
+    }
+
+    #[test]
+    fn distances_are_measured_between_edges() {
+        let first = BoundingBox::new(0.0, 0.0, 10.0, 10.0);
+
+        assert_eq!(first.distance(&BoundingBox::new(13.0, 14.0, 5.0, 5.0)), 5.0);
+        assert_eq!(first.distance(&BoundingBox::new(-8.0, 2.0, 5.0, 5.0)), 3.0);
+        assert_eq!(first.distance(&BoundingBox::new(2.0, -10.0, 5.0, 4.0)), 6.0);
+        assert_eq!(first.distance(&BoundingBox::new(10.0, 10.0, 5.0, 5.0)), 0.0);
+        assert_eq!(first.distance(&BoundingBox::new(5.0, 5.0, 10.0, 10.0)), 0.0);
+        assert_eq!(
+            first.distance(&BoundingBox::new(20.0, 5.0, 5.0, 5.0)),
+            BoundingBox::new(20.0, 5.0, 5.0, 5.0).distance(&first)
+        );
+    }
+}
@@ -0,0 +1,140 @@
+//! Column detection
+//!
+//! Columns are separated by vertical whitespace gutters. Gutters are found by projecting the tokens on the x axis: a gutter is a vertical band of the page crossed by (almost) no token and which is wider than the usual spacing between words.
+
+use crate::layout::{Line, Paragraph};
+use crate::raw_document::{Token, Tokens};
+use crate::traits::{Alignement, Coordinates, Shape, Spacing};
+
+/// A vertical band is considered as a gutter if it is crossed by less tokens than this ratio of the most crossed band of the page.
+///
+/// Some objects (titles, headers, footers…) usually span over several columns and are crossing gutters.
+const GUTTER_COVERAGE_RATIO: f32 = 0.1;
+
+/// A gutter should be wider than this number of horizontal spacing modes
+const GUTTER_SPACING_FACTOR: f32 = 3.0;
+
+/// Gutter minimal width (pt) used when the horizontal spacing mode is unknown
+const DEFAULT_GUTTER_WIDTH: f32 = 10.0;
+
+/// A struct representing a column of tokens
+#[derive(Debug, Clone)]
+pub struct Column<'a> {
+    pub tokens: Vec<&'a Token>,
+}
+
+impl<'a> Column<'a> {
+    /// Splits a set of tokens into columns.
+    ///
+    /// Columns are returned from left to right. Each token is assigned to exactly one column: the one in which its horizontal center falls. Tokens crossing a gutter are assigned to the column on the side of their center.
+    pub fn detect(tokens: &Tokens<'a>) -> Vec<Column<'a>> {
+        if tokens.tokens.is_empty() {
+            return vec![];
+        }
+
+        let gutters = Column::gutters(tokens);
+
+        let mut columns = vec![Column { tokens: vec![] }; gutters.len() + 1];
+
+        for token in tokens.tokens.iter().copied() {
+            let center = token.x() + token.width() / 2.0;
+            let index = gutters.iter().filter(|gutter| **gutter < center).count();
+
+            columns[index].tokens.push(token);
+        }
+
+        columns
+            .into_iter()
+            .filter(|column| !column.tokens.is_empty())
+            .collect()
+    }
+
+    /// Returns the horizontal center of every gutter of a set of tokens, from left to right
+    fn gutters(tokens: &Tokens) -> Vec<f32> {
+        use std::cmp::Ordering::Equal;
+
+        // Horizontal extents of the tokens, as changes of coverage sorted by position
+        let mut changes = tokens
+            .tokens
+            .iter()
+            .flat_map(|token| vec![(token.x(), 1_isize), (token.x() + token.width(), -1)])
+            .collect::<Vec<(f32, isize)>>();
+
+        // At the same position, tokens start before others end so that touching tokens leave no gap
+        changes.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Equal).then(b.1.cmp(&a.1)));
+
+        let left_bound = match changes.first() {
+            Some((x, _)) => *x,
+            None => return vec![],
+        };
+
+        // Number of tokens crossing each interval between two changes
+        let mut coverage = 0_isize;
+        let intervals = changes
+            .windows(2)
+            .map(|window| {
+                coverage += window[0].1;
+                (window[0].0, window[1].0, coverage.max(0) as usize)
+            })
+            .collect::<Vec<(f32, f32, usize)>>();
+
+        let max_coverage = intervals
+            .iter()
+            .map(|(_, _, count)| *count)
+            .max()
+            .unwrap_or(0);
+        let threshold = (max_coverage as f32 * GUTTER_COVERAGE_RATIO).floor() as usize;
+
+        let min_gutter_width = match tokens.mode_horizontal_spacing() {
+            Some(mode) if mode > 0.0 => mode * GUTTER_SPACING_FACTOR,
+            _ => DEFAULT_GUTTER_WIDTH,
+        };
+
+        let mut gutters = Vec::new();
+        let mut gutter_start: Option<f32> = None;
+
+        for (start, _, count) in intervals {
+            match (gutter_start, count <= threshold) {
+                (None, true) => gutter_start = Some(start),
+                (Some(gutter), false) => {
+                    // Whitespaces on the edges of the set are margins, not gutters
+                    if gutter > left_bound && start - gutter >= min_gutter_width {
+                        gutters.push((gutter + start) / 2.0);
+                    }
+                    gutter_start = None;
+                }
+                _ => (),
+            }
+        }
+
+        gutters
+    }
+
+    /// Returns all the tokens of the column
+    pub fn get_fsm_tokens(&self) -> Tokens<'a> {
+        Tokens {
+            tokens: self.tokens.clone(),
+        }
+    }
+
+    /// Returns all the lines of the column
+    pub fn get_lines(&self) -> Vec<Line<'a>> {
+        Line::detect(&self.get_fsm_tokens())
+    }
+
+    /// Returns all the paragraphs of the column
+    pub fn get_paragraphs(&self) -> Vec<Paragraph<'a>> {
+        Paragraph::detect(self.get_lines())
+    }
+}
+
+impl<'a> IntoIterator for Column<'a> {
+    type Item = &'a Token;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tokens.into_iter()
+    }
+}
+
+impl<'a> Alignement for Column<'a> {}
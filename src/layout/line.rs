@@ -0,0 +1,108 @@
+//! Line detection
+//!
+//! A line is a set of objects sharing the same base or a set of objects which are horizontally aligned. Horizontal spacing between objects shouldn't be greater than the horizontal spacing mode of the document, give or take a fraction of the font size.
+
+use crate::raw_document::{Token, Tokens};
+use crate::traits::{Alignement, Coordinates, ObjectAlignement, Shape, Spacing, Tolerance};
+
+use std::fmt;
+
+/// Tokens whose bases or y positions differ by less than this ratio of the font size are on the same line
+const LINE_TOLERANCE_RATIO: f32 = 0.2;
+
+/// Gaps between the words of a line may exceed the horizontal spacing mode by this ratio of the font size (e.g. justified text)
+const LINE_GAP_RATIO: f32 = 0.75;
+
+/// A struct representing a line of tokens
+#[derive(Debug, Clone)]
+pub struct Line<'a> {
+    pub tokens: Vec<&'a Token>,
+}
+
+impl<'a> Line<'a> {
+    /// Groups a set of tokens into lines.
+    ///
+    /// Tokens are read in document order. A token is added to the current line if it shares the base of the previous token or if both tokens are horizontally aligned (within a fifth of the font size), and if the gap between them isn't greater than the horizontal spacing mode of the set plus three quarters of the font size.
+    pub fn detect(tokens: &Tokens<'a>) -> Vec<Line<'a>> {
+        let mode_horizontal_spacing = tokens.mode_horizontal_spacing();
+
+        let mut lines = Vec::new();
+        let mut current_line: Vec<&'a Token> = Vec::new();
+
+        for token in tokens.tokens.iter().copied() {
+            if let Some(last_token) = current_line.last() {
+                if !Line::continues(last_token, token, mode_horizontal_spacing) {
+                    lines.push(Line {
+                        tokens: std::mem::take(&mut current_line),
+                    });
+                }
+            }
+
+            current_line.push(token);
+        }
+
+        if !current_line.is_empty() {
+            lines.push(Line {
+                tokens: current_line,
+            });
+        }
+
+        lines
+    }
+
+    /// Checks if a token continues the line ended by `last_token`
+    fn continues(last_token: &Token, token: &Token, mode_horizontal_spacing: Option<f32>) -> bool {
+        let tolerance = Tolerance::relative_to_font_size(last_token, LINE_TOLERANCE_RATIO);
+
+        let alignements = last_token.alignements(vec![token], tolerance);
+
+        let same_line = alignements.contains(ObjectAlignement::HorizontalBaselineAligned)
+            || alignements.contains(ObjectAlignement::HorizontalAligned);
+
+        // Spacing modes are computed on rounded values
+        let gap = (token.x() - (last_token.x() + last_token.width())).round();
+        let slack = Tolerance::relative_to_font_size(last_token, LINE_GAP_RATIO);
+
+        let close_enough = match mode_horizontal_spacing {
+            Some(mode) => gap >= 0.0 && gap <= mode + slack.points(),
+            None => gap >= 0.0,
+        };
+
+        same_line && close_enough
+    }
+
+    /// Returns the first token of the line
+    pub fn first_token(&self) -> Option<&Token> {
+        self.tokens.first().copied()
+    }
+
+    /// Returns the last token of the line
+    pub fn last_token(&self) -> Option<&Token> {
+        self.tokens.last().copied()
+    }
+}
+
+impl<'a> IntoIterator for Line<'a> {
+    type Item = &'a Token;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tokens.into_iter()
+    }
+}
+
+impl<'a> Alignement for Line<'a> {}
+
+impl<'a> fmt::Display for Line<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.tokens
+                .iter()
+                .map(|token| token.value.clone().unwrap_or_default())
+                .collect::<Vec<String>>()
+                .join(" ")
+        )
+    }
+}
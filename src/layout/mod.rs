@@ -0,0 +1,13 @@
+//! This module contains the layout detection algorithms.
+//!
+//! Layout objects are built on top of the raw tokens of a [`Document`](crate::Document). They borrow their tokens from the document and implement the same traits as the raw objects.
+
+mod column;
+mod line;
+mod orphan;
+mod paragraph;
+
+pub use column::Column;
+pub use line::Line;
+pub use orphan::{Orphan, OrphanKind};
+pub use paragraph::Paragraph;
@@ -0,0 +1,98 @@
+//! Orphans detection
+//!
+//! An orphan is the first line of a paragraph left alone at the bottom of a page or a column. A widow is the last line of a paragraph left alone at the top of a page or a column.
+//!
+//! Paragraph detection stops at page and column boundaries, so a paragraph split by a boundary is detected as two paragraphs. An orphan (or a widow) is a one-line paragraph that touches a boundary and shares the style of the paragraph on the other side of the boundary.
+
+use crate::layout::{Line, Paragraph};
+use crate::traits::Style;
+
+/// Kind of a line left alone at a boundary
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OrphanKind {
+    /// The first line of a paragraph is left alone at the bottom of a page or a column
+    Orphan,
+    /// The last line of a paragraph is left alone at the top of a page or a column
+    Widow,
+}
+
+/// A struct representing a line left alone at a page or column boundary
+#[derive(Debug, Clone)]
+pub struct Orphan<'p, 'a> {
+    pub kind: OrphanKind,
+    /// The flagged line
+    pub line: &'p Line<'a>,
+    /// The one-line part of the paragraph holding the flagged line, on its side of the boundary
+    pub fragment: &'p Paragraph<'a>,
+    /// The rest of the paragraph the flagged line belongs to, on the other side of the boundary
+    pub paragraph: &'p Paragraph<'a>,
+}
+
+impl<'p, 'a> Orphan<'p, 'a> {
+    /// Detects orphans and widows among paragraphs.
+    ///
+    /// `paragraphs` holds the paragraphs of each page or column, in reading order. A boundary is assumed between two consecutive sets of paragraphs.
+    ///
+    /// ```no_run
+    /// # use pdf_shape::*;
+    /// # fn example(document: &Document) {
+    /// let paragraphs = document
+    ///     .get_pages_borrowed()
+    ///     .into_iter()
+    ///     .flat_map(|page| page.get_columns())
+    ///     .map(|column| column.get_paragraphs())
+    ///     .collect::<Vec<Vec<Paragraph>>>();
+    ///
+    /// for orphan in Orphan::detect(&paragraphs) {
+    ///     println!("{:?} : {:?}", orphan.kind, orphan.token_ids());
+    /// }
+    /// # }
+    /// ```
+    pub fn detect(paragraphs: &'p [Vec<Paragraph<'a>>]) -> Vec<Orphan<'p, 'a>> {
+        let mut orphans = Vec::new();
+
+        for boundary in paragraphs.windows(2) {
+            let (before, after) = match (boundary[0].last(), boundary[1].first()) {
+                (Some(before), Some(after)) => (before, after),
+                _ => continue,
+            };
+
+            if !Orphan::same_style(before, after) {
+                continue;
+            }
+
+            if let [line] = before.lines.as_slice() {
+                orphans.push(Orphan {
+                    kind: OrphanKind::Orphan,
+                    line,
+                    fragment: before,
+                    paragraph: after,
+                });
+            }
+
+            if let [line] = after.lines.as_slice() {
+                orphans.push(Orphan {
+                    kind: OrphanKind::Widow,
+                    line,
+                    fragment: after,
+                    paragraph: before,
+                });
+            }
+        }
+
+        orphans
+    }
+
+    /// Checks if two parts of a paragraph share the same style
+    fn same_style(before: &Paragraph, after: &Paragraph) -> bool {
+        before.font_size().is_some()
+            && before.font_size() == after.font_size()
+            && before.bold() == after.bold()
+            && before.italic() == after.italic()
+    }
+
+    /// Returns the ids of the tokens of the flagged line
+    pub fn token_ids(&self) -> Vec<&'a str> {
+        self.line.tokens.iter().map(|token| token.id()).collect()
+    }
+}
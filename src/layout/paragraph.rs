@@ -0,0 +1,121 @@
+//! Paragraph detection
+//!
+//! A paragraph is a set of lines that are equally spaced vertically. In most cases the paragraph spacing should be greater than the document line spacing. Each paragraph lines have to be vertically aligned.
+
+use crate::layout::Line;
+use crate::raw_document::{Token, Tokens};
+use crate::traits::{Alignement, Coordinates, ObjectAlignement, Spacing, Tolerance};
+
+use std::fmt;
+
+/// Lines whose edges or centers differ by less than this ratio of the font size are vertically aligned
+const PARAGRAPH_TOLERANCE_RATIO: f32 = 0.5;
+
+/// Spacing between the lines of a paragraph may exceed the vertical spacing mode by this ratio of the font size
+const PARAGRAPH_SPACING_RATIO: f32 = 0.25;
+
+/// A struct representing a paragraph, a set of lines
+#[derive(Debug, Clone)]
+pub struct Paragraph<'a> {
+    pub lines: Vec<Line<'a>>,
+}
+
+impl<'a> Paragraph<'a> {
+    /// Groups a set of lines into paragraphs.
+    ///
+    /// Lines are read in document order. A line is added to the current paragraph if the vertical spacing with the previous line isn't greater than the vertical spacing mode of the set of lines plus a quarter of the font size, and if both lines are vertically aligned (left, right or center, within half the font size).
+    pub fn detect(lines: Vec<Line<'a>>) -> Vec<Paragraph<'a>> {
+        let mode_vertical_spacing = lines.iter().mode_vertical_spacing();
+
+        let mut paragraphs = Vec::new();
+        let mut current_paragraph: Vec<Line<'a>> = Vec::new();
+
+        for line in lines {
+            if let Some(last_line) = current_paragraph.last() {
+                if !Paragraph::continues(last_line, &line, mode_vertical_spacing) {
+                    paragraphs.push(Paragraph {
+                        lines: std::mem::take(&mut current_paragraph),
+                    });
+                }
+            }
+
+            current_paragraph.push(line);
+        }
+
+        if !current_paragraph.is_empty() {
+            paragraphs.push(Paragraph {
+                lines: current_paragraph,
+            });
+        }
+
+        paragraphs
+    }
+
+    /// Checks if a line continues the paragraph ended by `last_line`
+    fn continues(last_line: &Line, line: &Line, mode_vertical_spacing: Option<f32>) -> bool {
+        let tolerance = Tolerance::relative_to_font_size(last_line, PARAGRAPH_TOLERANCE_RATIO);
+
+        let alignements = last_line.alignements(vec![line], tolerance);
+
+        let aligned = alignements.contains(ObjectAlignement::VerticalLeftAligned)
+            || alignements.contains(ObjectAlignement::VerticalRightAlgined)
+            || alignements.contains(ObjectAlignement::VerticalCenterAlgined);
+
+        // Spacing modes are computed on rounded values
+        let spacing = (line.y() - last_line.base()).round();
+        let slack = Tolerance::relative_to_font_size(last_line, PARAGRAPH_SPACING_RATIO);
+
+        let equally_spaced = match mode_vertical_spacing {
+            Some(mode) => spacing >= 0.0 && spacing <= mode + slack.points(),
+            None => spacing >= 0.0,
+        };
+
+        aligned && equally_spaced
+    }
+
+    /// Returns all the tokens of the paragraph
+    pub fn tokens(&self) -> Tokens<'a> {
+        Tokens {
+            tokens: self
+                .lines
+                .iter()
+                .flat_map(|line| line.tokens.iter().copied())
+                .collect::<Vec<&'a Token>>(),
+        }
+    }
+
+    /// Returns the first line of the paragraph
+    pub fn first_line(&self) -> Option<&Line<'a>> {
+        self.lines.first()
+    }
+
+    /// Returns the last line of the paragraph
+    pub fn last_line(&self) -> Option<&Line<'a>> {
+        self.lines.last()
+    }
+}
+
+impl<'a> IntoIterator for Paragraph<'a> {
+    type Item = &'a Token;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tokens().into_iter()
+    }
+}
+
+impl<'a> Alignement for Paragraph<'a> {}
+
+impl<'a> fmt::Display for Paragraph<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.lines
+                .iter()
+                .map(|line| line.to_string())
+                .collect::<Vec<String>>()
+                .join("\n")
+        )
+    }
+}
@@ -2,21 +2,31 @@
 //!
 //! Pdf-shape is a Rust library dedicated to analyse XML files produced by [pdf2xml](https://github.com/kermitt2/pdf2xml)
 //!
+//! ALTO files produced by [pdfalto](https://github.com/kermitt2/pdfalto), XML files produced by poppler's `pdftohtml -xml` and hOCR files produced by OCR engines can also be loaded with [`Document::from_alto_path`], [`Document::from_pdftohtml_path`] and [`Document::from_hocr_path`]. They are mapped to the same model so that all the traits work on every format. Documents and their detected layout can be written back as hOCR.
+//!
 //! Implemented :
 //! - Alignement extraction
 //! - Coordinates extraction
-//! - Shape extraction
+//! - Shape extraction (get the bounding box of any object or set of objects, with union, intersection, IoU and distances)
 //! - Spacing extraction
 //! - Style extraction
 //! - Style extraction
-//! - Blocks extraction (get all the block elements of a given document)
-//! - Texts extraction (get all the text elements of a given document)
-//! - Tokens extraction (get all the token elements of a given document)
-//!
-//! Not yet implemented
+//! - Metadata extraction (source PDF, pdf2xml version, command line flags and creation date)
+//! - Outline extraction (get the bookmarks of the outline file of a given document, with the pages and headings they point to)
+//! - Pages extraction (get all the page elements of a given document, or read them one at a time from large files)
+//! - Blocks extraction (get all the block elements of a given document or page)
+//! - Texts extraction (get all the text elements of a given document or page)
+//! - Images extraction (get all the image elements of a given document or page)
+//! - Links extraction (get the hyperlinks of the annotations file of a given document, page or set of tokens)
+//! - Vector graphics extraction (get the paths, rectangles and lines of the `.vec` file of a given document or page)
+//! - Tokens extraction (get all the token elements of a given document or page)
+//! - Spatial queries (get the tokens, texts or blocks of a page within a region, nearest to an object or next to it in a given direction)
 //! - Line detection
-//! - Column detection
 //! - Paragraph detection
+//! - Column detection
+//! - Orphans detection
+//! - Serialization (write a document, including any edits, back to the pdf2xml schema)
+//! - JSON export (write a document and its spacing and alignement analyses with a versioned JSON schema)
 //!
 //! ## Shape and Spacing
 //!
@@ -27,23 +37,39 @@
 //!
 //! ## Line detection
 //!
-//! A line is a set of objects sharing the same base or a set of objects which are horizontally aligned. Horizontal spacing between objects shouldn't be greater than the horizontal spacing mode of the document.
+//! A line is a set of objects sharing the same base or a set of objects which are horizontally aligned. Horizontal spacing between objects shouldn't be greater than the horizontal spacing mode of the document, give or take a fraction of the font size.
 //!
 //! ![Diagram lines detection](../../../images/lines.svg)
 //!
 //! ## Columns detection
 //!
+//! Columns are separated by vertical whitespace gutters. A gutter is a vertical band crossed by (almost) no object and which is wider than the horizontal spacing mode of the document. Objects spanning over several columns (titles, headers…) are ignored while looking for gutters.
+//!
 //! ## Paragraph detection
 //!
 //! A paragraph is a set of lines that are equally spaced vertically. In most cases the paragraph spacing should be greater than the document line spacing. Each paragraph lines have to be vertically aligned.
 //!
 //! ### Orphans detection
 //!
+//! An orphan is the first line of a paragraph left alone at the bottom of a page or a column. A widow is the last line of a paragraph left alone at the top of a page or a column.
+//!
 //! ![Diagram orphans detection](../../../images/orphans.svg)
 //!
 
+mod error;
+mod geometry;
+mod layout;
 mod raw_document;
+mod spatial;
 mod traits;
 
-pub use raw_document::Document;
+pub use error::Error;
+pub use geometry::BoundingBox;
+pub use layout::{Column, Line, Orphan, OrphanKind, Paragraph};
+pub use raw_document::{
+    Block, Color, DeserizalizationTokens, Document, Font, FontStyle, FontWeight, Image,
+    JsonOptions, LineSegment, Link, LinkTarget, Metadata, Outline, OutlineEntry, Page, PageReader,
+    Path, PathSegment, Rect, Text, Token, Tokens, JSON_SCHEMA_VERSION,
+};
+pub use spatial::{Direction, SpatialIndex};
 pub use traits::*;
@@ -0,0 +1,343 @@
+//! Loading of ALTO XML files produced by [pdfalto](https://github.com/kermitt2/pdfalto).
+//!
+//! ALTO elements are mapped to the pdf2xml model : `Page` elements become pages, `TextBlock` elements become blocks, `TextLine` elements become texts and `String` elements become tokens, and `Illustration` elements become images. Styles referenced by `STYLEREFS` attributes are resolved into font family, font size, font color, bold and italic. Tokens without any style reference are sized by their height.
+//!
+//! Positions are converted to points according to the `MeasurementUnit` of the document : `mm10` and `inch1200` values are scaled, while `pixel` values are kept as they are since pdfalto writes them at 72 dpi. Font sizes are always given in points.
+
+use super::attributes::Attributes;
+use super::{parent, Block, DeserizalizationTokens, Document, Image, Page, Text, Token};
+use crate::error::Error;
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// A struct representing an ALTO `TextStyle` element
+struct TextStyle {
+    font_family: Option<String>,
+    font_size: Option<f32>,
+    font_color: Option<String>,
+    font_style: Option<String>,
+    font_type: Option<String>,
+    font_width: Option<String>,
+}
+
+/// The unit of the positions of an ALTO document
+#[derive(Debug, Clone, Copy)]
+enum MeasurementUnit {
+    /// Pixels, read as points
+    Pixel,
+    /// Tenths of a millimeter
+    Mm10,
+    /// 1/1200 of an inch
+    Inch1200,
+}
+
+impl MeasurementUnit {
+    fn parse(unit: &str) -> Result<MeasurementUnit, Error> {
+        match unit.trim() {
+            "pixel" => Ok(MeasurementUnit::Pixel),
+            "mm10" => Ok(MeasurementUnit::Mm10),
+            "inch1200" => Ok(MeasurementUnit::Inch1200),
+            unit => Err(Error::UnsupportedUnit(unit.to_string())),
+        }
+    }
+
+    /// Converts a value of this unit to points
+    fn points(self, value: f32) -> f32 {
+        match self {
+            MeasurementUnit::Pixel => value,
+            MeasurementUnit::Mm10 => value * 72.0 / 254.0,
+            MeasurementUnit::Inch1200 => value * 72.0 / 1200.0,
+        }
+    }
+}
+
+/// Styles referenced by a `String` element and its parents
+struct StyleReferences {
+    token_id: String,
+    style_refs: Option<String>,
+    font_style: Option<String>,
+}
+
+impl Document {
+    /// Reads and parses an ALTO file
+    pub fn from_alto_path<P: AsRef<Path>>(path: P) -> Result<Document, Error> {
+        Document::from_alto_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Parses an ALTO document
+    pub fn from_alto_str(xml: &str) -> Result<Document, Error> {
+        Document::from_alto_reader(xml.as_bytes())
+    }
+
+    /// Reads and parses an ALTO document from a buffered reader
+    pub fn from_alto_reader<R: BufRead>(reader: R) -> Result<Document, Error> {
+        let mut reader = Reader::from_reader(reader);
+        let mut buffer = Vec::new();
+
+        let mut styles: HashMap<String, TextStyle> = HashMap::new();
+        let mut references: Vec<StyleReferences> = Vec::new();
+        let mut pages: Vec<Page> = Vec::new();
+
+        let mut unit = MeasurementUnit::Pixel;
+        let mut block_style_refs: Option<String> = None;
+        let mut block_rotation = 0.0;
+        let mut line_style_refs: Option<String> = None;
+        let mut line_baseline: Option<f32> = None;
+
+        loop {
+            let (element, empty) = match reader.read_event(&mut buffer)? {
+                Event::Start(element) => (element.into_owned(), false),
+                Event::Empty(element) => (element.into_owned(), true),
+                Event::End(element) => {
+                    match element.local_name() {
+                        b"TextBlock" => block_style_refs = None,
+                        b"TextLine" => line_style_refs = None,
+                        _ => (),
+                    }
+
+                    buffer.clear();
+                    continue;
+                }
+                Event::Eof => break,
+                _ => {
+                    buffer.clear();
+                    continue;
+                }
+            };
+
+            match element.local_name() {
+                b"MeasurementUnit" if !empty => {
+                    unit = MeasurementUnit::parse(&reader.read_text(element.name(), &mut buffer)?)?;
+                }
+                b"TextStyle" => {
+                    let attributes = Attributes::read("TextStyle", &element)?;
+
+                    styles.insert(
+                        attributes.required("ID")?.to_string(),
+                        TextStyle {
+                            font_family: attributes.get("FONTFAMILY").map(String::from),
+                            font_size: attributes.float("FONTSIZE")?,
+                            font_color: attributes.get("FONTCOLOR").map(String::from),
+                            font_style: attributes.get("FONTSTYLE").map(String::from),
+                            font_type: attributes.get("FONTTYPE").map(String::from),
+                            font_width: attributes.get("FONTWIDTH").map(String::from),
+                        },
+                    );
+                }
+                b"Page" => {
+                    let attributes = Attributes::read("Page", &element)?;
+                    let number = attributes
+                        .integer("PHYSICAL_IMG_NR")?
+                        .unwrap_or(pages.len() + 1);
+
+                    pages.push(Page {
+                        id: attributes.id().unwrap_or_else(|| format!("p{}", number)),
+                        number,
+                        width: attributes.float("WIDTH")?.map(|value| unit.points(value)),
+                        height: attributes.float("HEIGHT")?.map(|value| unit.points(value)),
+                        blocks: vec![],
+                        images: vec![],
+                        paths: vec![],
+                        links: vec![],
+                    });
+                }
+                b"TextBlock" => {
+                    let attributes = Attributes::read("TextBlock", &element)?;
+                    let page = parent(pages.last_mut(), "TextBlock", "Page")?;
+
+                    if !empty {
+                        block_style_refs = attributes.get("STYLEREFS").map(String::from);
+                        block_rotation = attributes.float("ROTATION")?.unwrap_or_default();
+                    }
+
+                    page.blocks.push(Block {
+                        id: attributes
+                            .id()
+                            .unwrap_or_else(|| format!("{}_b{}", page.id, page.blocks.len() + 1)),
+                        x: unit.points(attributes.required_float("HPOS")?),
+                        y: unit.points(attributes.required_float("VPOS")?),
+                        width: attributes.float("WIDTH")?.map(|value| unit.points(value)),
+                        height: attributes.float("HEIGHT")?.map(|value| unit.points(value)),
+                        texts: vec![],
+                    });
+                }
+                b"Illustration" => {
+                    let attributes = Attributes::read("Illustration", &element)?;
+                    let page = parent(pages.last_mut(), "Illustration", "Page")?;
+
+                    page.images.push(Image::new(
+                        attributes
+                            .id()
+                            .unwrap_or_else(|| format!("{}_i{}", page.id, page.images.len() + 1)),
+                        unit.points(attributes.required_float("HPOS")?),
+                        unit.points(attributes.required_float("VPOS")?),
+                        unit.points(attributes.required_float("WIDTH")?),
+                        unit.points(attributes.required_float("HEIGHT")?),
+                        attributes.get("FILEREF").map(String::from),
+                    ));
+                }
+                b"TextLine" => {
+                    let attributes = Attributes::read("TextLine", &element)?;
+                    let page = parent(pages.last_mut(), "TextLine", "Page")?;
+                    let page_id = page.id.clone();
+                    let block = parent(page.blocks.last_mut(), "TextLine", "TextBlock")?;
+
+                    if !empty {
+                        line_style_refs = attributes.get("STYLEREFS").map(String::from);
+                        // BASELINE may be a list of points in recent ALTO versions
+                        line_baseline = attributes
+                            .get("BASELINE")
+                            .and_then(|baseline| baseline.trim().parse::<f32>().ok())
+                            .map(|baseline| unit.points(baseline));
+                    }
+
+                    let count = block.texts.len() + 1;
+
+                    block.texts.push(Text {
+                        id: attributes
+                            .id()
+                            .unwrap_or_else(|| format!("{}_t{}", page_id, count)),
+                        x: unit.points(attributes.required_float("HPOS")?),
+                        y: unit.points(attributes.required_float("VPOS")?),
+                        width: unit.points(attributes.required_float("WIDTH")?),
+                        height: unit.points(attributes.required_float("HEIGHT")?),
+                        tokens: DeserizalizationTokens(vec![]),
+                    });
+                }
+                b"String" => {
+                    let attributes = Attributes::read("String", &element)?;
+                    let page = parent(pages.last_mut(), "String", "Page")?;
+                    let id = attributes
+                        .id()
+                        .unwrap_or_else(|| format!("{}_w{}", page.id, references.len() + 1));
+                    let block = parent(page.blocks.last_mut(), "String", "TextBlock")?;
+                    let text = parent(block.texts.last_mut(), "String", "TextLine")?;
+
+                    let y = unit.points(attributes.required_float("VPOS")?);
+                    let height = unit.points(attributes.required_float("HEIGHT")?);
+
+                    references.push(StyleReferences {
+                        token_id: id.clone(),
+                        style_refs: attributes
+                            .get("STYLEREFS")
+                            .map(String::from)
+                            .or_else(|| line_style_refs.clone())
+                            .or_else(|| block_style_refs.clone()),
+                        font_style: attributes.get("STYLE").map(String::from),
+                    });
+
+                    text.tokens.0.push(Token {
+                        sid: None,
+                        id,
+                        font_name: None,
+                        fixed_width: None,
+                        serif: None,
+                        symbolic: None,
+                        links: vec![],
+                        bold: false,
+                        italic: false,
+                        font_color: String::from("#000000"),
+                        font_size: 0.0,
+                        rotation: block_rotation,
+                        angle: 0.0,
+                        x: unit.points(attributes.required_float("HPOS")?),
+                        y,
+                        base: line_baseline.unwrap_or(y + height),
+                        width: unit.points(attributes.required_float("WIDTH")?),
+                        height,
+                        value: Some(attributes.required("CONTENT")?.to_string()),
+                    });
+                }
+                _ => (),
+            }
+
+            buffer.clear();
+        }
+
+        let tokens = pages
+            .iter_mut()
+            .flat_map(|page| &mut page.blocks)
+            .flat_map(|block| &mut block.texts)
+            .flat_map(|text| &mut text.tokens.0);
+
+        for (token, references) in tokens.zip(references) {
+            apply_style(token, &references, &styles)?;
+        }
+
+        Ok(Document {
+            metadata: None,
+            pages,
+            outline: None,
+        })
+    }
+}
+
+/// Resolves the styles referenced by a `String` element and applies them to its token
+///
+/// `STYLEREFS` is optional: tokens without any style reference keep a default style whose font size is their height.
+fn apply_style(
+    token: &mut Token,
+    references: &StyleReferences,
+    styles: &HashMap<String, TextStyle>,
+) -> Result<(), Error> {
+    let style = match &references.style_refs {
+        // STYLEREFS may reference both text and paragraph styles
+        Some(style_refs) => Some(
+            style_refs
+                .split_whitespace()
+                .find_map(|style_ref| styles.get(style_ref).map(|style| (style_ref, style)))
+                .ok_or_else(|| Error::UnresolvedReference {
+                    element: "String",
+                    attribute: "STYLEREFS",
+                    reference: style_refs.clone(),
+                    id: Some(references.token_id.clone()),
+                })?,
+        ),
+        None => None,
+    };
+
+    token.font_size = token.height;
+
+    if let Some((style_ref, style)) = style {
+        token.font_size = style.font_size.ok_or_else(|| Error::MissingAttribute {
+            element: "TextStyle",
+            attribute: "FONTSIZE",
+            id: Some(style_ref.to_string()),
+        })?;
+
+        token.font_name = style.font_family.clone();
+
+        token.serif = match style.font_type.as_deref() {
+            Some("serif") => Some(true),
+            Some("sans-serif") => Some(false),
+            _ => None,
+        };
+
+        token.fixed_width = match style.font_width.as_deref() {
+            Some("fixed") => Some(true),
+            Some("proportional") => Some(false),
+            _ => None,
+        };
+
+        if let Some(font_color) = &style.font_color {
+            token.font_color = format!("#{}", font_color.trim_start_matches('#'));
+        }
+    }
+
+    let font_style = references
+        .font_style
+        .as_ref()
+        .or_else(|| style.and_then(|(_, style)| style.font_style.as_ref()))
+        .map(|font_style| font_style.split_whitespace().collect::<Vec<&str>>())
+        .unwrap_or_default();
+
+    token.bold = font_style.contains(&"bold");
+    token.italic = font_style.contains(&"italics") || font_style.contains(&"italic");
+
+    Ok(())
+}
@@ -0,0 +1,304 @@
+//! Loading of the annotations file written by pdf2xml next to the XML file.
+//!
+//! Each `ANNOTATION` element describes the areas of a page covered by the annotation (`QUADPOINTS`, four `POINT` elements per area) and the `ACTION` triggered when it is clicked. Link annotations pointing to an URI or to a destination of the document become [`Link`]s, which are attached to the page and to every token they cover.
+
+use super::attributes::Attributes;
+use super::{Document, Page};
+use crate::error::Error;
+use crate::geometry::BoundingBox;
+use crate::traits::{Alignement, Coordinates, Shape};
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path as FilePath;
+
+/// The target of a link
+#[derive(Debug, Clone, PartialEq)]
+pub enum LinkTarget {
+    /// An external resource (e.g. `https://doi.org/10.1000/182`)
+    Uri(String),
+    /// A position in the document
+    Destination {
+        page: Option<usize>,
+        x: Option<f32>,
+        y: Option<f32>,
+    },
+}
+
+/// A struct representing a link annotation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Link {
+    page: usize,
+    target: LinkTarget,
+    areas: Vec<BoundingBox>,
+}
+
+impl Link {
+    /// Returns the number of the page of the link
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Returns the target of the link
+    pub fn target(&self) -> &LinkTarget {
+        &self.target
+    }
+
+    /// Returns the URI of the link
+    ///
+    /// ⚠️ This method returns `None` if the link points to a destination of the document
+    pub fn uri(&self) -> Option<&str> {
+        match &self.target {
+            LinkTarget::Uri(uri) => Some(uri),
+            LinkTarget::Destination { .. } => None,
+        }
+    }
+
+    /// Returns the areas covered by the link
+    pub fn areas(&self) -> &[BoundingBox] {
+        &self.areas
+    }
+
+    /// Checks if an object is covered by one of the areas of the link
+    pub fn overlaps<T: Coordinates + Shape>(&self, object: &T) -> bool {
+        let bounding_box = object.bounding_box();
+
+        self.areas.iter().any(|area| area.overlaps(&bounding_box))
+    }
+
+    /// Returns the smallest box enclosing all the areas of the link
+    fn enclosing_box(&self) -> BoundingBox {
+        BoundingBox::enclosing(self.areas.iter().copied())
+            .unwrap_or_else(|| BoundingBox::new(0.0, 0.0, 0.0, 0.0))
+    }
+}
+
+impl Coordinates for Link {
+    fn x(&self) -> f32 {
+        self.enclosing_box().x()
+    }
+
+    fn y(&self) -> f32 {
+        self.enclosing_box().y()
+    }
+
+    fn base(&self) -> f32 {
+        self.enclosing_box().bottom()
+    }
+}
+
+impl Shape for Link {
+    fn width(&self) -> f32 {
+        self.enclosing_box().width()
+    }
+
+    fn height(&self) -> f32 {
+        self.enclosing_box().height()
+    }
+
+    fn rotation(&self) -> Option<f32> {
+        None
+    }
+
+    fn angle(&self) -> Option<f32> {
+        None
+    }
+}
+
+impl Alignement for Link {}
+
+/// An `ANNOTATION` element being read
+#[derive(Default)]
+struct PendingAnnotation {
+    page: Option<usize>,
+    action: Option<String>,
+    uri: String,
+    destination: Option<(Option<usize>, Option<f32>, Option<f32>)>,
+    points: Vec<(f32, f32)>,
+    in_destination: bool,
+}
+
+impl PendingAnnotation {
+    /// Builds the link described by the annotation
+    ///
+    /// ⚠️ This method returns `None` if the annotation is not a link or doesn't cover any area
+    fn into_link(self) -> Option<Link> {
+        let target = match self.action.as_deref() {
+            Some("uri") => LinkTarget::Uri(self.uri.trim().to_string()),
+            Some("goto") | Some("gotor") => {
+                let (page, x, y) = self.destination.unwrap_or_default();
+                LinkTarget::Destination { page, x, y }
+            }
+            _ => return None,
+        };
+
+        // Each area is described by the four corners of a quadrilateral
+        let areas = self
+            .points
+            .chunks_exact(4)
+            .map(|corners| {
+                let left = corners.iter().map(|c| c.0).fold(f32::INFINITY, f32::min);
+                let right = corners
+                    .iter()
+                    .map(|c| c.0)
+                    .fold(f32::NEG_INFINITY, f32::max);
+                let top = corners.iter().map(|c| c.1).fold(f32::INFINITY, f32::min);
+                let bottom = corners
+                    .iter()
+                    .map(|c| c.1)
+                    .fold(f32::NEG_INFINITY, f32::max);
+
+                BoundingBox::from_edges(left, top, right, bottom)
+            })
+            .collect::<Vec<BoundingBox>>();
+
+        if areas.is_empty() {
+            return None;
+        }
+
+        Some(Link {
+            page: self.page.unwrap_or(1),
+            target,
+            areas,
+        })
+    }
+}
+
+impl Document {
+    /// Reads an annotations file written by pdf2xml and attaches its links to the pages and tokens of the document
+    pub fn load_annotations_path<P: AsRef<FilePath>>(&mut self, path: P) -> Result<(), Error> {
+        self.load_annotations_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Parses the content of an annotations file and attaches its links to the pages and tokens of the document
+    pub fn load_annotations_str(&mut self, xml: &str) -> Result<(), Error> {
+        self.load_annotations_reader(xml.as_bytes())
+    }
+
+    /// Reads an annotations file from a buffered reader and attaches its links to the pages and tokens of the document
+    ///
+    /// Annotations which are not links (e.g. comments) are ignored.
+    pub fn load_annotations_reader<R: BufRead>(&mut self, reader: R) -> Result<(), Error> {
+        let mut reader = Reader::from_reader(reader);
+        let mut buffer = Vec::new();
+
+        let mut annotation: Option<PendingAnnotation> = None;
+
+        loop {
+            let event = reader.read_event(&mut buffer)?;
+
+            match event {
+                Event::Start(ref element) | Event::Empty(ref element) => {
+                    let empty = matches!(event, Event::Empty(_));
+
+                    match element.name() {
+                        b"ANNOTATION" => {
+                            let attributes = Attributes::read("ANNOTATION", element)?;
+
+                            annotation = Some(PendingAnnotation {
+                                page: attributes.integer("pagenum")?,
+                                ..PendingAnnotation::default()
+                            });
+                        }
+                        b"ACTION" => {
+                            let attributes = Attributes::read("ACTION", element)?;
+
+                            if let Some(annotation) = annotation.as_mut() {
+                                annotation.action = attributes.get("type").map(str::to_lowercase);
+                            }
+                        }
+                        b"DEST" => {
+                            let attributes = Attributes::read("DEST", element)?;
+
+                            if let Some(annotation) = annotation.as_mut() {
+                                annotation.in_destination = !empty;
+                                annotation.destination = Some((
+                                    attributes.integer("page")?,
+                                    attributes.float("left")?,
+                                    attributes.float("top")?,
+                                ));
+                            }
+                        }
+                        b"POINT" => {
+                            let attributes = Attributes::read("POINT", element)?;
+
+                            if let Some(annotation) = annotation.as_mut() {
+                                annotation.points.push((
+                                    attributes.required_float("HPOS")?,
+                                    attributes.required_float("VPOS")?,
+                                ));
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+                Event::Text(ref content) => {
+                    if let Some(annotation) = annotation.as_mut() {
+                        if annotation.in_destination {
+                            annotation
+                                .uri
+                                .push_str(&String::from_utf8_lossy(&content.unescaped()?));
+                        }
+                    }
+                }
+                Event::End(ref element) => match element.name() {
+                    b"DEST" => {
+                        if let Some(annotation) = annotation.as_mut() {
+                            annotation.in_destination = false;
+                        }
+                    }
+                    b"ANNOTATION" => {
+                        if let Some(link) = annotation.take().and_then(PendingAnnotation::into_link)
+                        {
+                            self.push_link(link);
+                        }
+                    }
+                    _ => (),
+                },
+                Event::Eof => break,
+                _ => (),
+            }
+
+            buffer.clear();
+        }
+
+        Ok(())
+    }
+
+    /// Attaches a link to its page and to the tokens it covers
+    fn push_link(&mut self, link: Link) {
+        let index = match self.pages.iter().position(|page| page.number == link.page) {
+            Some(index) => index,
+            None => {
+                // Pages are kept sorted by number
+                let index = self
+                    .pages
+                    .iter()
+                    .position(|page| page.number > link.page)
+                    .unwrap_or(self.pages.len());
+
+                self.pages
+                    .insert(index, Page::without_geometry(link.page, Vec::new()));
+                index
+            }
+        };
+
+        let page = &mut self.pages[index];
+
+        let tokens = page
+            .blocks
+            .iter_mut()
+            .flat_map(|block| block.texts.iter_mut())
+            .flat_map(|text| text.tokens.0.iter_mut());
+
+        for token in tokens {
+            if link.overlaps(token) {
+                token.links.push(link.clone());
+            }
+        }
+
+        page.links.push(link);
+    }
+}
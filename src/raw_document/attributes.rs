@@ -0,0 +1,88 @@
+//! Attributes of an XML element read with quick-xml events.
+
+use crate::error::Error;
+
+use quick_xml::events::BytesStart;
+
+/// The decoded attributes of an element
+pub(crate) struct Attributes {
+    element: &'static str,
+    values: Vec<(String, String)>,
+}
+
+impl Attributes {
+    /// Decodes the attributes of an element
+    pub(crate) fn read(element: &'static str, start: &BytesStart) -> Result<Attributes, Error> {
+        let mut values = Vec::new();
+
+        for attribute in start.attributes() {
+            let attribute = attribute?;
+
+            values.push((
+                String::from_utf8_lossy(attribute.key).into_owned(),
+                String::from_utf8_lossy(&attribute.unescaped_value()?).into_owned(),
+            ));
+        }
+
+        Ok(Attributes { element, values })
+    }
+
+    /// Returns the value of an attribute
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the id of the element (`id` or `ID` attribute)
+    pub(crate) fn id(&self) -> Option<String> {
+        self.get("id").or_else(|| self.get("ID")).map(String::from)
+    }
+
+    /// Returns the value of a required attribute
+    pub(crate) fn required(&self, name: &'static str) -> Result<&str, Error> {
+        self.get(name).ok_or_else(|| Error::MissingAttribute {
+            element: self.element,
+            attribute: name,
+            id: self.id(),
+        })
+    }
+
+    /// Parses the value of an attribute as a float
+    pub(crate) fn float(&self, name: &'static str) -> Result<Option<f32>, Error> {
+        self.get(name)
+            .map(|value| {
+                value.trim().parse::<f32>().map_err(|_| Error::ParseNumber {
+                    element: self.element,
+                    attribute: name,
+                    value: value.to_string(),
+                    id: self.id(),
+                })
+            })
+            .transpose()
+    }
+
+    /// Parses the value of a required attribute as a float
+    pub(crate) fn required_float(&self, name: &'static str) -> Result<f32, Error> {
+        self.required(name)?;
+        self.float(name).map(|value| value.unwrap_or_default())
+    }
+
+    /// Parses the value of an attribute as an integer
+    pub(crate) fn integer(&self, name: &'static str) -> Result<Option<usize>, Error> {
+        self.get(name)
+            .map(|value| {
+                value
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|_| Error::ParseNumber {
+                        element: self.element,
+                        attribute: name,
+                        value: value.to_string(),
+                        id: self.id(),
+                    })
+            })
+            .transpose()
+    }
+}
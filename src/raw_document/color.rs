@@ -0,0 +1,152 @@
+//! Colours of tokens.
+//!
+//! pdf2xml writes colours as hexadecimal RGB strings (e.g. `#000000`).
+
+use std::fmt;
+
+/// A struct representing an RGB colour
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+impl Color {
+    /// Creates a colour from its RGB components
+    pub fn new(red: u8, green: u8, blue: u8) -> Color {
+        Color { red, green, blue }
+    }
+
+    /// Parses an hexadecimal colour (`#rrggbb` or `#rgb`, the leading `#` is optional)
+    ///
+    /// ⚠️ This method returns `None` if the value is not a valid hexadecimal colour
+    pub fn from_hex(value: &str) -> Option<Color> {
+        let hex = value.trim().trim_start_matches('#');
+
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        match hex.len() {
+            6 => Some(Color::new(
+                u8::from_str_radix(&hex[0..2], 16).ok()?,
+                u8::from_str_radix(&hex[2..4], 16).ok()?,
+                u8::from_str_radix(&hex[4..6], 16).ok()?,
+            )),
+            3 => {
+                let component = |i: usize| u8::from_str_radix(&hex[i..=i], 16).map(|c| c * 17);
+
+                Some(Color::new(
+                    component(0).ok()?,
+                    component(1).ok()?,
+                    component(2).ok()?,
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the red component of the colour
+    pub fn red(&self) -> u8 {
+        self.red
+    }
+
+    /// Returns the green component of the colour
+    pub fn green(&self) -> u8 {
+        self.green
+    }
+
+    /// Returns the blue component of the colour
+    pub fn blue(&self) -> u8 {
+        self.blue
+    }
+
+    /// Returns the RGB components of the colour
+    pub fn rgb(&self) -> (u8, u8, u8) {
+        (self.red, self.green, self.blue)
+    }
+
+    /// Returns the relative luminance of the colour, between 0 (black) and 1 (white)
+    ///
+    /// The luminance is computed from linearised sRGB components as defined by WCAG 2
+    pub fn luminance(&self) -> f32 {
+        let linearize = |component: u8| {
+            let c = component as f32 / 255.0;
+
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        0.2126 * linearize(self.red)
+            + 0.7152 * linearize(self.green)
+            + 0.0722 * linearize(self.blue)
+    }
+
+    /// Returns the euclidean distance between two colours in the RGB space (between 0 and ~441.7)
+    pub fn distance(&self, other: &Color) -> f32 {
+        let delta = |a: u8, b: u8| (a as f32 - b as f32).powi(2);
+
+        (delta(self.red, other.red) + delta(self.green, other.green) + delta(self.blue, other.blue))
+            .sqrt()
+    }
+
+    /// Checks if two colours are the same, allowing their distance to be at most `tolerance`
+    pub fn approx_eq(&self, other: &Color, tolerance: f32) -> bool {
+        self.distance(other) <= tolerance
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hexadecimal_colors_are_parsed() {
+        assert_eq!(Color::from_hex("#ff8000"), Some(Color::new(255, 128, 0)));
+        assert_eq!(Color::from_hex("FF8000"), Some(Color::new(255, 128, 0)));
+        assert_eq!(Color::from_hex(" #f80 "), Some(Color::new(255, 136, 0)));
+        assert_eq!(Color::from_hex("#ff80"), None);
+        assert_eq!(Color::from_hex("#gg8000"), None);
+        assert_eq!(Color::from_hex("#+f8000"), None);
+        assert_eq!(Color::from_hex(""), None);
+    }
+
+    #[test]
+    fn colors_are_written_as_hexadecimal() {
+        assert_eq!(Color::new(255, 128, 0).to_string(), "#ff8000");
+        assert_eq!(Color::from_hex("#F80").unwrap().to_string(), "#ff8800");
+    }
+
+    #[test]
+    fn luminance_goes_from_black_to_white() {
+        assert_eq!(Color::new(0, 0, 0).luminance(), 0.0);
+        assert!((Color::new(255, 255, 255).luminance() - 1.0).abs() < 1e-6);
+        assert!((Color::new(255, 0, 0).luminance() - 0.2126).abs() < 1e-6);
+        assert!((Color::new(128, 128, 128).luminance() - 0.2159).abs() < 1e-3);
+        assert!(Color::new(0, 255, 0).luminance() > Color::new(255, 0, 0).luminance());
+    }
+
+    #[test]
+    fn distance_is_euclidean() {
+        let black = Color::new(0, 0, 0);
+        let white = Color::new(255, 255, 255);
+
+        assert_eq!(black.distance(&black), 0.0);
+        assert_eq!(Color::new(3, 4, 0).distance(&black), 5.0);
+        assert_eq!(black.distance(&white), white.distance(&black));
+        assert!((black.distance(&white) - 441.673).abs() < 1e-3);
+
+        assert!(Color::new(10, 10, 10).approx_eq(&black, 18.0));
+        assert!(!Color::new(10, 10, 10).approx_eq(&black, 17.0));
+    }
+}
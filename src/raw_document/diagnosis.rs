@@ -0,0 +1,120 @@
+//! Serde errors don't tell which element of a document is faulty. This module reads a document event by event to find the first element which doesn't match the pdf2xml schema.
+
+use super::attributes::Attributes;
+use crate::error::Error;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+/// Attributes of a pdf2xml element
+struct ElementSchema {
+    name: &'static str,
+    required: &'static [&'static str],
+    floats: &'static [&'static str],
+    integers: &'static [&'static str],
+}
+
+const SCHEMA: &[ElementSchema] = &[
+    ElementSchema {
+        name: "PAGE",
+        required: &["id", "number"],
+        floats: &["width", "height"],
+        integers: &["number"],
+    },
+    ElementSchema {
+        name: "BLOCK",
+        required: &["id", "x", "y"],
+        floats: &["x", "y", "width", "height"],
+        integers: &[],
+    },
+    ElementSchema {
+        name: "TEXT",
+        required: &["id", "x", "y", "width", "height"],
+        floats: &["x", "y", "width", "height"],
+        integers: &[],
+    },
+    ElementSchema {
+        name: "IMAGE",
+        required: &["id", "x", "y", "width", "height"],
+        floats: &["x", "y", "width", "height"],
+        integers: &[],
+    },
+    ElementSchema {
+        name: "TOKEN",
+        required: &[
+            "id",
+            "bold",
+            "italic",
+            "font-color",
+            "font-size",
+            "rotation",
+            "angle",
+            "x",
+            "y",
+            "base",
+            "width",
+            "height",
+        ],
+        floats: &[
+            "font-size",
+            "rotation",
+            "angle",
+            "x",
+            "y",
+            "base",
+            "width",
+            "height",
+        ],
+        integers: &[],
+    },
+];
+
+/// Returns the first schema error of a document
+///
+/// ⚠️ This function returns `None` if no element breaks the schema
+pub(crate) fn diagnose(xml: &str) -> Option<Error> {
+    let mut reader = Reader::from_str(xml);
+    let mut buffer = Vec::new();
+
+    loop {
+        match reader.read_event(&mut buffer) {
+            Ok(Event::Start(ref element)) | Ok(Event::Empty(ref element)) => {
+                if let Err(error) = check_element(element) {
+                    return Some(error);
+                }
+            }
+            Ok(Event::Eof) => return None,
+            Err(error) => return Some(Error::Xml(error)),
+            _ => (),
+        }
+
+        buffer.clear();
+    }
+}
+
+/// Checks the attributes of an element against the pdf2xml schema
+fn check_element(element: &BytesStart) -> Result<(), Error> {
+    let schema = match SCHEMA
+        .iter()
+        .find(|schema| schema.name.as_bytes() == element.name())
+    {
+        Some(schema) => schema,
+        None => return Ok(()),
+    };
+
+    let attributes = Attributes::read(schema.name, element)?;
+
+    for attribute in schema.required {
+        attributes.required(attribute)?;
+    }
+
+    for attribute in schema.floats {
+        attributes.float(attribute)?;
+    }
+
+    for attribute in schema.integers {
+        attributes.integer(attribute)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,229 @@
+//! Fonts of tokens.
+//!
+//! pdf2xml describes the font of a token with a single `font-name` attribute made of a family and of modifiers (e.g. `cambria,bold`). PostScript names (e.g. `Times-BoldItalic`) and the subset prefixes of embedded fonts (e.g. `ABCDEF+Cambria`) are also understood.
+
+/// Weight of a font
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FontWeight {
+    Thin,
+    Light,
+    Regular,
+    Medium,
+    SemiBold,
+    Bold,
+    ExtraBold,
+    Black,
+}
+
+/// Style of a font
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FontStyle {
+    Normal,
+    Italic,
+    Oblique,
+}
+
+/// A struct representing the font of a token
+#[derive(Debug, Clone, PartialEq)]
+pub struct Font {
+    family: String,
+    weight: FontWeight,
+    style: FontStyle,
+    modifiers: Vec<String>,
+}
+
+impl Font {
+    /// Parses a font name (e.g. `cambria,bold` or `Times-BoldItalic`)
+    ///
+    /// The subset prefix of embedded fonts (e.g. `ABCDEF+Cambria`) is dropped. In PostScript names, only the trailing parts naming a weight or a style are modifiers, so that hyphenated families (e.g. `Noto-Sans-Bold`) are kept whole.
+    pub fn parse(font_name: &str) -> Font {
+        let font_name = match font_name.split_once('+') {
+            Some((prefix, name))
+                if prefix.len() == 6 && prefix.bytes().all(|byte| byte.is_ascii_uppercase()) =>
+            {
+                name
+            }
+            _ => font_name,
+        };
+
+        let (family, modifiers) = if font_name.contains(',') {
+            let mut parts = font_name.split(',').map(str::trim);
+            let family = parts.next().unwrap_or_default();
+
+            (family.to_string(), parts.collect::<Vec<&str>>())
+        } else {
+            let mut parts = font_name.split('-').map(str::trim).collect::<Vec<&str>>();
+            let family_length = parts
+                .iter()
+                .skip(1)
+                .rposition(|part| !Font::is_modifier(part))
+                .map_or(1, |position| position + 2);
+            let modifiers = parts.split_off(family_length);
+
+            (parts.join("-"), modifiers)
+        };
+
+        let modifiers = modifiers
+            .into_iter()
+            .filter(|modifier| !modifier.is_empty())
+            .map(|modifier| modifier.to_lowercase())
+            .collect::<Vec<String>>();
+
+        let weight = modifiers
+            .iter()
+            .find_map(|modifier| Font::weight_from_modifier(modifier))
+            .unwrap_or(FontWeight::Regular);
+
+        let style = if modifiers.iter().any(|modifier| modifier.contains("italic")) {
+            FontStyle::Italic
+        } else if modifiers
+            .iter()
+            .any(|modifier| modifier.contains("oblique"))
+        {
+            FontStyle::Oblique
+        } else {
+            FontStyle::Normal
+        };
+
+        Font {
+            family,
+            weight,
+            style,
+            modifiers,
+        }
+    }
+
+    /// Checks if a part of a PostScript name names a weight or a style (e.g. `BoldItalic`)
+    fn is_modifier(part: &str) -> bool {
+        let part = part.to_lowercase();
+
+        part.is_empty()
+            || Font::weight_from_modifier(&part).is_some()
+            || part.contains("italic")
+            || part.contains("oblique")
+    }
+
+    fn weight_from_modifier(modifier: &str) -> Option<FontWeight> {
+        // Compound weights are checked before the simple ones
+        const WEIGHTS: &[(&str, FontWeight)] = &[
+            ("semibold", FontWeight::SemiBold),
+            ("demibold", FontWeight::SemiBold),
+            ("extrabold", FontWeight::ExtraBold),
+            ("ultrabold", FontWeight::ExtraBold),
+            ("bold", FontWeight::Bold),
+            ("black", FontWeight::Black),
+            ("heavy", FontWeight::Black),
+            ("medium", FontWeight::Medium),
+            ("light", FontWeight::Light),
+            ("thin", FontWeight::Thin),
+            ("hairline", FontWeight::Thin),
+            ("regular", FontWeight::Regular),
+        ];
+
+        WEIGHTS
+            .iter()
+            .find(|(name, _)| modifier.contains(name))
+            .map(|(_, weight)| *weight)
+    }
+
+    /// Returns the family of the font (e.g. `cambria`)
+    pub fn family(&self) -> &str {
+        &self.family
+    }
+
+    /// Returns the weight of the font
+    pub fn weight(&self) -> FontWeight {
+        self.weight
+    }
+
+    /// Returns the style of the font
+    pub fn style(&self) -> FontStyle {
+        self.style
+    }
+
+    /// Returns all the modifiers of the font name, lowercased (e.g. `["bold"]`)
+    pub fn modifiers(&self) -> Vec<&str> {
+        self.modifiers.iter().map(String::as_str).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pdf2xml_font_names_are_parsed() {
+        let font = Font::parse("cambria,bold");
+
+        assert_eq!(font.family(), "cambria");
+        assert_eq!(font.weight(), FontWeight::Bold);
+        assert_eq!(font.style(), FontStyle::Normal);
+        assert_eq!(font.modifiers(), vec!["bold"]);
+
+        let font = Font::parse("Noto-Sans, Italic");
+
+        assert_eq!(font.family(), "Noto-Sans");
+        assert_eq!(font.weight(), FontWeight::Regular);
+        assert_eq!(font.style(), FontStyle::Italic);
+    }
+
+    #[test]
+    fn postscript_font_names_are_parsed() {
+        let font = Font::parse("Times-BoldItalic");
+
+        assert_eq!(font.family(), "Times");
+        assert_eq!(font.weight(), FontWeight::Bold);
+        assert_eq!(font.style(), FontStyle::Italic);
+        assert_eq!(font.modifiers(), vec!["bolditalic"]);
+
+        let font = Font::parse("Helvetica");
+
+        assert_eq!(font.family(), "Helvetica");
+        assert_eq!(font.weight(), FontWeight::Regular);
+        assert!(font.modifiers().is_empty());
+    }
+
+    #[test]
+    fn hyphenated_families_are_kept() {
+        let font = Font::parse("Noto-Sans-Bold");
+
+        assert_eq!(font.family(), "Noto-Sans");
+        assert_eq!(font.weight(), FontWeight::Bold);
+        assert_eq!(font.modifiers(), vec!["bold"]);
+
+        let font = Font::parse("Source-Sans-Pro-Light-Oblique");
+
+        assert_eq!(font.family(), "Source-Sans-Pro");
+        assert_eq!(font.weight(), FontWeight::Light);
+        assert_eq!(font.style(), FontStyle::Oblique);
+    }
+
+    #[test]
+    fn subset_prefixes_are_dropped() {
+        assert_eq!(Font::parse("ABCDEF+Cambria-Bold").family(), "Cambria");
+        assert_eq!(Font::parse("QWERTY+cambria,bold").family(), "cambria");
+        // Only six uppercase letters make a prefix
+        assert_eq!(Font::parse("ABC+Cambria").family(), "ABC+Cambria");
+    }
+
+    #[test]
+    fn compound_weights_are_parsed() {
+        assert_eq!(
+            Font::parse("Roboto-SemiBoldItalic").weight(),
+            FontWeight::SemiBold
+        );
+        assert_eq!(
+            Font::parse("Roboto-DemiBold").weight(),
+            FontWeight::SemiBold
+        );
+        assert_eq!(
+            Font::parse("Roboto-ExtraBold").weight(),
+            FontWeight::ExtraBold
+        );
+        assert_eq!(
+            Font::parse("arial,ultrabold").weight(),
+            FontWeight::ExtraBold
+        );
+        assert_eq!(Font::parse("Roboto-Black").weight(), FontWeight::Black);
+    }
+}
@@ -0,0 +1,676 @@
+//! Import and export of [hOCR](http://kba.cloud/hocr-spec/1.2/) documents.
+//!
+//! hOCR elements are mapped to the pdf2xml model : `ocr_page` elements become pages, `ocr_carea` elements become blocks, `ocr_line` elements (and the other line classes) become texts and `ocrx_word` elements become tokens. Paragraphs (`ocr_par`) are not kept by the model, but detected paragraphs can be written back with [`Document::write_hocr_layout`].
+//!
+//! Lines found outside of any `ocr_carea` element get their own block, and words found outside of any line their own text. The bases of the tokens are read from the `baseline` property of their line, and written back the same way.
+
+use super::attributes::Attributes;
+use super::{parent, Block, DeserizalizationTokens, Document, Page, Text, Token};
+use crate::error::Error;
+use crate::geometry::BoundingBox;
+use crate::layout::{Line, Paragraph};
+use crate::traits::Shape;
+
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::{Reader, Writer};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+
+/// hOCR classes describing lines
+const LINE_CLASSES: &[&str] = &["ocr_line", "ocr_header", "ocr_caption", "ocr_textfloat"];
+
+/// HTML elements that are never closed
+const VOID_ELEMENTS: &[&[u8]] = &[b"meta", b"link", b"br", b"img", b"hr", b"input"];
+
+/// Role of an element of an hOCR document
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Role {
+    Page,
+    Area,
+    Line,
+    Word,
+    Bold,
+    Italic,
+    Other,
+}
+
+/// The line being read, its properties apply to its words
+struct CurrentLine {
+    bbox: BoundingBox,
+    /// Slope and offset of the baseline, relative to the bottom left corner of the line
+    baseline: Option<(f32, f32)>,
+    font_size: Option<f32>,
+}
+
+impl Document {
+    /// Reads and parses an hOCR file
+    pub fn from_hocr_path<P: AsRef<Path>>(path: P) -> Result<Document, Error> {
+        Document::from_hocr_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Parses an hOCR document
+    pub fn from_hocr_str(html: &str) -> Result<Document, Error> {
+        Document::from_hocr_reader(html.as_bytes())
+    }
+
+    /// Reads and parses an hOCR document from a buffered reader
+    pub fn from_hocr_reader<R: BufRead>(reader: R) -> Result<Document, Error> {
+        let mut reader = Reader::from_reader(reader);
+        reader.check_end_names(false);
+
+        let mut buffer = Vec::new();
+        let mut pages: Vec<Page> = Vec::new();
+        let mut roles: Vec<Role> = Vec::new();
+        let mut line: Option<CurrentLine> = None;
+        let mut word_count = 0;
+
+        loop {
+            match reader.read_event(&mut buffer)? {
+                Event::Start(element) => {
+                    let role =
+                        read_element(&element, &roles, &mut pages, &mut line, &mut word_count)?;
+
+                    if !VOID_ELEMENTS.contains(&element.name()) {
+                        roles.push(role);
+                    }
+                }
+                Event::Empty(element) => {
+                    read_element(&element, &roles, &mut pages, &mut line, &mut word_count)?;
+                }
+                Event::Text(content) if roles.contains(&Role::Word) => {
+                    let content = String::from_utf8_lossy(&content.unescaped()?).into_owned();
+
+                    if let Some(token) = pages
+                        .last_mut()
+                        .and_then(|page| page.blocks.last_mut())
+                        .and_then(|block| block.texts.last_mut())
+                        .and_then(|text| text.tokens.0.last_mut())
+                    {
+                        token.bold |= roles.contains(&Role::Bold) && !content.trim().is_empty();
+                        token.italic |= roles.contains(&Role::Italic) && !content.trim().is_empty();
+                        token.value = Some(token.value.take().unwrap_or_default() + &content);
+                    }
+                }
+                Event::End(_) => {
+                    // Words belong to the current line until it is closed
+                    let role = roles.pop();
+
+                    if role == Some(Role::Line) {
+                        line = None;
+                    }
+                }
+                Event::Eof => break,
+                _ => (),
+            }
+
+            buffer.clear();
+        }
+
+        // Whitespaces around words are not part of the words, and blank words have no value like empty pdf2xml tokens
+        for token in pages
+            .iter_mut()
+            .flat_map(|page| &mut page.blocks)
+            .flat_map(|block| &mut block.texts)
+            .flat_map(|text| &mut text.tokens.0)
+        {
+            token.value = token
+                .value
+                .take()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty());
+        }
+
+        Ok(Document {
+            metadata: None,
+            pages,
+            outline: None,
+        })
+    }
+
+    /// Writes the pages, blocks, texts and tokens of a document as an hOCR document
+    ///
+    /// Blocks are written as `ocr_carea` elements, texts as `ocr_line` elements and tokens as `ocrx_word` elements.
+    pub fn write_hocr<W: Write>(&self, writer: W) -> Result<(), Error> {
+        let mut hocr = HocrWriter::new(writer, "ocr_page ocr_carea ocr_line ocrx_word")?;
+
+        for page in &self.pages {
+            hocr.open_page(page)?;
+
+            for block in &page.blocks {
+                hocr.open("div", "ocr_carea", &block.id, block.bounding_box(), "")?;
+
+                for text in &block.texts {
+                    let bbox = text.bounding_box();
+                    hocr.open(
+                        "span",
+                        "ocr_line",
+                        &text.id,
+                        bbox,
+                        &baseline(&text.tokens.0, bbox),
+                    )?;
+
+                    for token in &text.tokens.0 {
+                        hocr.word(token)?;
+                    }
+
+                    hocr.close("span")?;
+                }
+
+                hocr.close("div")?;
+            }
+
+            hocr.close("div")?;
+        }
+
+        hocr.end()
+    }
+
+    /// Writes the detected layout of a document as an hOCR document
+    ///
+    /// Columns are written as `ocr_carea` elements, paragraphs as `ocr_par` elements, lines as `ocr_line` elements and tokens as `ocrx_word` elements.
+    pub fn write_hocr_layout<W: Write>(&self, writer: W) -> Result<(), Error> {
+        let mut hocr = HocrWriter::new(writer, "ocr_page ocr_carea ocr_par ocr_line ocrx_word")?;
+
+        for page in &self.pages {
+            hocr.open_page(page)?;
+
+            let mut paragraph_count = 0;
+            let mut line_count = 0;
+
+            for (column_index, column) in page.get_columns().iter().enumerate() {
+                let column_id = format!("{}_c{}", page.id, column_index + 1);
+                hocr.open(
+                    "div",
+                    "ocr_carea",
+                    &column_id,
+                    tokens_bbox(column.tokens.iter().copied()),
+                    "",
+                )?;
+
+                for paragraph in column.get_paragraphs() {
+                    paragraph_count += 1;
+                    hocr.write_paragraph(
+                        &paragraph,
+                        &format!("{}_par{}", page.id, paragraph_count),
+                        &mut line_count,
+                        &page.id,
+                    )?;
+                }
+
+                hocr.close("div")?;
+            }
+
+            hocr.close("div")?;
+        }
+
+        hocr.end()
+    }
+}
+
+/// Returns the role of an element from its name and its `class` attribute
+fn role(element: &BytesStart) -> Result<Role, Error> {
+    match element.name() {
+        b"strong" | b"b" => return Ok(Role::Bold),
+        b"em" | b"i" => return Ok(Role::Italic),
+        _ => (),
+    }
+
+    for attribute in element.attributes() {
+        let attribute = attribute?;
+
+        if attribute.key == b"class" {
+            let value = attribute.unescaped_value()?;
+            let classes = String::from_utf8_lossy(&value);
+            let classes = classes.split_whitespace().collect::<Vec<&str>>();
+
+            return Ok(if classes.contains(&"ocr_page") {
+                Role::Page
+            } else if classes.contains(&"ocr_carea") {
+                Role::Area
+            } else if classes.iter().any(|class| LINE_CLASSES.contains(class)) {
+                Role::Line
+            } else if classes.contains(&"ocrx_word") {
+                Role::Word
+            } else {
+                Role::Other
+            });
+        }
+    }
+
+    Ok(Role::Other)
+}
+
+/// Reads the start of an element and adds the object it describes to the document
+///
+/// Lines found outside of any `ocr_carea` element are wrapped into their own block, and words found outside of any line into their own text, like the `TEXT` elements of pdf2xml files without blocks.
+fn read_element(
+    element: &BytesStart,
+    ancestors: &[Role],
+    pages: &mut Vec<Page>,
+    line: &mut Option<CurrentLine>,
+    word_count: &mut usize,
+) -> Result<Role, Error> {
+    let role = role(element)?;
+
+    match role {
+        Role::Page => {
+            let attributes = Attributes::read("ocr_page", element)?;
+            let title = attributes.get("title").unwrap_or_default();
+            let bbox = bbox(&attributes)?;
+
+            let number = match property(title, "ppageno").and_then(|values| values.first().copied())
+            {
+                Some(number) => number
+                    .parse::<usize>()
+                    .map(|number| number + 1)
+                    .map_err(|_| Error::ParseNumber {
+                        element: "ocr_page",
+                        attribute: "title",
+                        value: title.to_string(),
+                        id: attributes.id(),
+                    })?,
+                None => pages.len() + 1,
+            };
+
+            pages.push(Page {
+                id: attributes.id().unwrap_or_else(|| format!("p{}", number)),
+                number,
+                width: Some(bbox.width()),
+                height: Some(bbox.height()),
+                blocks: vec![],
+                images: vec![],
+                paths: vec![],
+                links: vec![],
+            });
+        }
+        Role::Area => {
+            let attributes = Attributes::read("ocr_carea", element)?;
+            let bbox = bbox(&attributes)?;
+            let page = parent(pages.last_mut(), "ocr_carea", "ocr_page")?;
+
+            page.blocks.push(Block {
+                id: attributes
+                    .id()
+                    .unwrap_or_else(|| format!("{}_b{}", page.id, page.blocks.len() + 1)),
+                x: bbox.x(),
+                y: bbox.y(),
+                width: Some(bbox.width()),
+                height: Some(bbox.height()),
+                texts: vec![],
+            });
+        }
+        Role::Line => {
+            let attributes = Attributes::read("ocr_line", element)?;
+            let title = attributes.get("title").unwrap_or_default();
+            let bbox = bbox(&attributes)?;
+            let page = parent(pages.last_mut(), "ocr_line", "ocr_page")?;
+
+            if !ancestors.contains(&Role::Area) {
+                push_block(page, bbox);
+            }
+
+            *line = Some(CurrentLine {
+                bbox,
+                baseline: match property(title, "baseline").as_deref() {
+                    Some([slope, offset]) => slope.parse().ok().zip(offset.parse().ok()),
+                    _ => None,
+                },
+                font_size: property(title, "x_size")
+                    .and_then(|values| values.first().and_then(|size| size.parse().ok())),
+            });
+
+            push_text(page, attributes.id(), bbox, "ocr_line")?;
+        }
+        Role::Word => {
+            let attributes = Attributes::read("ocrx_word", element)?;
+            let title = attributes.get("title").unwrap_or_default();
+            let bbox = bbox(&attributes)?;
+            let page = parent(pages.last_mut(), "ocrx_word", "ocr_page")?;
+
+            if !ancestors.contains(&Role::Area) && !ancestors.contains(&Role::Line) {
+                push_block(page, bbox);
+            }
+
+            if !ancestors.contains(&Role::Line) {
+                push_text(page, None, bbox, "ocrx_word")?;
+            }
+
+            let page_id = page.id.clone();
+            let block = parent(page.blocks.last_mut(), "ocrx_word", "ocr_carea")?;
+            let text = parent(block.texts.last_mut(), "ocrx_word", "ocr_line")?;
+            let current_line = line.as_ref();
+
+            *word_count += 1;
+
+            let base = match current_line
+                .and_then(|line| line.baseline.map(|baseline| (line.bbox, baseline)))
+            {
+                Some((line_bbox, (slope, offset))) => {
+                    line_bbox.bottom() + offset + slope * (bbox.x() - line_bbox.x())
+                }
+                None => bbox.bottom(),
+            };
+
+            let font_size = property(title, "x_fsize")
+                .and_then(|values| values.first().and_then(|size| size.parse().ok()))
+                .or_else(|| current_line.and_then(|line| line.font_size))
+                .unwrap_or_else(|| bbox.height());
+
+            text.tokens.0.push(Token {
+                sid: None,
+                id: attributes
+                    .id()
+                    .unwrap_or_else(|| format!("{}_w{}", page_id, word_count)),
+                font_name: None,
+                fixed_width: None,
+                serif: None,
+                symbolic: None,
+                links: vec![],
+                bold: false,
+                italic: false,
+                font_color: String::from("#000000"),
+                font_size,
+                rotation: 0.0,
+                angle: 0.0,
+                x: bbox.x(),
+                y: bbox.y(),
+                base,
+                width: bbox.width(),
+                height: bbox.height(),
+                value: None,
+            });
+        }
+        _ => (),
+    }
+
+    Ok(role)
+}
+
+/// Adds a block to a page
+fn push_block(page: &mut Page, bbox: BoundingBox) {
+    page.blocks.push(Block {
+        id: format!("{}_b{}", page.id, page.blocks.len() + 1),
+        x: bbox.x(),
+        y: bbox.y(),
+        width: Some(bbox.width()),
+        height: Some(bbox.height()),
+        texts: vec![],
+    });
+}
+
+/// Adds a text to the last block of a page, texts without id being numbered across the page
+fn push_text(
+    page: &mut Page,
+    id: Option<String>,
+    bbox: BoundingBox,
+    element: &'static str,
+) -> Result<(), Error> {
+    let count = page
+        .blocks
+        .iter()
+        .map(|block| block.texts.len())
+        .sum::<usize>()
+        + 1;
+    let id = id.unwrap_or_else(|| format!("{}_t{}", page.id, count));
+
+    parent(page.blocks.last_mut(), element, "ocr_carea")?
+        .texts
+        .push(Text {
+            id,
+            x: bbox.x(),
+            y: bbox.y(),
+            width: bbox.width(),
+            height: bbox.height(),
+            tokens: DeserizalizationTokens(vec![]),
+        });
+
+    Ok(())
+}
+
+/// Returns the values of a property of an hOCR title attribute (e.g. `bbox 0 0 10 10; x_wconf 95`)
+fn property<'a>(title: &'a str, name: &str) -> Option<Vec<&'a str>> {
+    title.split(';').find_map(|property| {
+        let mut values = property.split_whitespace();
+
+        if values.next() == Some(name) {
+            Some(values.collect())
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns the bounding box of an hOCR element
+fn bbox(attributes: &Attributes) -> Result<BoundingBox, Error> {
+    let title = attributes.required("title")?;
+
+    let invalid = || Error::ParseNumber {
+        element: "bbox",
+        attribute: "title",
+        value: title.to_string(),
+        id: attributes.id(),
+    };
+
+    let values = property(title, "bbox")
+        .ok_or_else(|| Error::MissingAttribute {
+            element: "bbox",
+            attribute: "title",
+            id: attributes.id(),
+        })?
+        .iter()
+        .map(|value| value.parse::<f32>().map_err(|_| invalid()))
+        .collect::<Result<Vec<f32>, Error>>()?;
+
+    match values.as_slice() {
+        [x0, y0, x1, y1] => Ok(BoundingBox::from_edges(*x0, *y0, *x1, *y1)),
+        _ => Err(invalid()),
+    }
+}
+
+/// Returns the bounding box of a set of tokens
+fn tokens_bbox<'a>(tokens: impl IntoIterator<Item = &'a Token>) -> BoundingBox {
+    BoundingBox::enclosing(tokens.into_iter().map(|token| token.bounding_box()))
+        .unwrap_or_else(|| BoundingBox::new(0.0, 0.0, 0.0, 0.0))
+}
+
+/// Returns the `baseline` property of a line, going through the bases of its first and last tokens
+///
+/// The offset is relative to the bottom left corner of the line, as written in its rounded `bbox` property.
+fn baseline<'a>(tokens: impl IntoIterator<Item = &'a Token>, bbox: BoundingBox) -> String {
+    let tokens = tokens.into_iter().collect::<Vec<&Token>>();
+
+    match (tokens.first(), tokens.last()) {
+        (Some(first), Some(last)) => {
+            let slope = if last.x > first.x {
+                (last.base - first.base) / (last.x - first.x)
+            } else {
+                0.0
+            };
+            let offset =
+                first.base - bbox.bottom().round() - slope * (first.x - bbox.left().round());
+
+            format!("; baseline {} {}", slope, offset)
+        }
+        _ => String::new(),
+    }
+}
+
+/// Writes hOCR elements
+struct HocrWriter<W: Write> {
+    writer: Writer<W>,
+}
+
+impl<W: Write> HocrWriter<W> {
+    /// Writes the header of an hOCR document
+    fn new(writer: W, capabilities: &str) -> Result<Self, Error> {
+        let mut writer = Writer::new_with_indent(writer, b' ', 1);
+
+        writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), None)))?;
+        writer.write_event(Event::DocType(BytesText::from_escaped_str(
+            r#" html PUBLIC "-//W3C//DTD XHTML 1.0 Transitional//EN" "http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd""#,
+        )))?;
+
+        let mut html = BytesStart::borrowed_name(b"html");
+        html.push_attribute(("xmlns", "http://www.w3.org/1999/xhtml"));
+        writer.write_event(Event::Start(html))?;
+        writer.write_event(Event::Start(BytesStart::borrowed_name(b"head")))?;
+
+        writer.write_event(Event::Start(BytesStart::borrowed_name(b"title")))?;
+        writer.write_event(Event::End(BytesEnd::borrowed(b"title")))?;
+
+        let mut content_type = BytesStart::borrowed_name(b"meta");
+        content_type.push_attribute(("http-equiv", "Content-Type"));
+        content_type.push_attribute(("content", "text/html;charset=utf-8"));
+        writer.write_event(Event::Empty(content_type))?;
+
+        let mut system = BytesStart::borrowed_name(b"meta");
+        system.push_attribute(("name", "ocr-system"));
+        system.push_attribute(("content", "pdf-shape"));
+        writer.write_event(Event::Empty(system))?;
+
+        let mut capabilities_meta = BytesStart::borrowed_name(b"meta");
+        capabilities_meta.push_attribute(("name", "ocr-capabilities"));
+        capabilities_meta.push_attribute(("content", capabilities));
+        writer.write_event(Event::Empty(capabilities_meta))?;
+
+        writer.write_event(Event::End(BytesEnd::borrowed(b"head")))?;
+        writer.write_event(Event::Start(BytesStart::borrowed_name(b"body")))?;
+
+        Ok(HocrWriter { writer })
+    }
+
+    /// Opens an hOCR element
+    fn open(
+        &mut self,
+        tag: &str,
+        class: &str,
+        id: &str,
+        bbox: BoundingBox,
+        properties: &str,
+    ) -> Result<(), Error> {
+        let title = format!(
+            "bbox {} {} {} {}{}",
+            bbox.left().round(),
+            bbox.top().round(),
+            bbox.right().round(),
+            bbox.bottom().round(),
+            properties
+        );
+
+        let mut element = BytesStart::borrowed_name(tag.as_bytes());
+        element.push_attribute(("class", class));
+        element.push_attribute(("id", id));
+        element.push_attribute(("title", title.as_str()));
+
+        self.writer.write_event(Event::Start(element))?;
+
+        Ok(())
+    }
+
+    /// Closes an hOCR element
+    fn close(&mut self, tag: &str) -> Result<(), Error> {
+        self.writer
+            .write_event(Event::End(BytesEnd::borrowed(tag.as_bytes())))?;
+
+        Ok(())
+    }
+
+    /// Opens an `ocr_page` element
+    fn open_page(&mut self, page: &Page) -> Result<(), Error> {
+        let bbox = match (page.width, page.height) {
+            (Some(width), Some(height)) => BoundingBox::new(0.0, 0.0, width, height),
+            _ => {
+                let tokens = tokens_bbox(page.get_fsm_tokens());
+                BoundingBox::from_edges(0.0, 0.0, tokens.right().max(0.0), tokens.bottom().max(0.0))
+            }
+        };
+
+        self.open(
+            "div",
+            "ocr_page",
+            &page.id,
+            bbox,
+            &format!("; ppageno {}", page.number.saturating_sub(1)),
+        )
+    }
+
+    /// Writes an `ocr_par` element holding the lines of a paragraph
+    fn write_paragraph(
+        &mut self,
+        paragraph: &Paragraph,
+        id: &str,
+        line_count: &mut usize,
+        page_id: &str,
+    ) -> Result<(), Error> {
+        self.open("p", "ocr_par", id, tokens_bbox(paragraph.tokens()), "")?;
+
+        for line in &paragraph.lines {
+            *line_count += 1;
+            self.write_line(line, &format!("{}_l{}", page_id, line_count))?;
+        }
+
+        self.close("p")
+    }
+
+    /// Writes an `ocr_line` element holding the tokens of a line
+    fn write_line(&mut self, line: &Line, id: &str) -> Result<(), Error> {
+        let bbox = tokens_bbox(line.tokens.iter().copied());
+        self.open(
+            "span",
+            "ocr_line",
+            id,
+            bbox,
+            &baseline(line.tokens.iter().copied(), bbox),
+        )?;
+
+        for token in &line.tokens {
+            self.word(token)?;
+        }
+
+        self.close("span")
+    }
+
+    /// Writes an `ocrx_word` element
+    fn word(&mut self, token: &Token) -> Result<(), Error> {
+        self.open(
+            "span",
+            "ocrx_word",
+            &token.id,
+            token.bounding_box(),
+            &format!("; x_fsize {}", token.font_size),
+        )?;
+
+        let value = token.value.clone().unwrap_or_default();
+        let mut tags = Vec::new();
+
+        if token.bold {
+            tags.push("strong");
+        }
+
+        if token.italic {
+            tags.push("em");
+        }
+
+        for tag in &tags {
+            self.writer
+                .write_event(Event::Start(BytesStart::borrowed_name(tag.as_bytes())))?;
+        }
+
+        self.writer
+            .write_event(Event::Text(BytesText::from_plain_str(&value)))?;
+
+        for tag in tags.iter().rev() {
+            self.writer
+                .write_event(Event::End(BytesEnd::borrowed(tag.as_bytes())))?;
+        }
+
+        self.close("span")
+    }
+
+    /// Writes the end of an hOCR document
+    fn end(&mut self) -> Result<(), Error> {
+        self.close("body")?;
+        self.close("html")
+    }
+}
@@ -0,0 +1,115 @@
+//! The `IMAGE` elements of a pdf2xml file.
+//!
+//! pdf2xml writes embedded pictures as `IMAGE` elements holding their position and a reference to the extracted file. Images can be used to mask text regions or to pair figures with their captions.
+
+use super::{Token, Tokens};
+use crate::traits::{Alignement, Coordinates, Shape};
+
+use serde::Deserialize;
+
+/// A struct representing an image element of a Document
+#[derive(Debug, Deserialize, Clone)]
+pub struct Image {
+    sid: Option<String>,
+    id: String,
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    href: Option<String>,
+}
+
+impl Image {
+    /// Creates an image from its position, for the loaders of the other formats
+    pub(super) fn new(
+        id: String,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        href: Option<String>,
+    ) -> Image {
+        Image {
+            sid: None,
+            id,
+            x,
+            y,
+            width,
+            height,
+            href,
+        }
+    }
+
+    /// Returns the id of the image
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the sid of the image
+    pub fn sid(&self) -> Option<&str> {
+        self.sid.as_deref()
+    }
+
+    /// Returns the path of the file extracted by pdf2xml for this image
+    ///
+    /// ⚠️ This method returns `None` if the `IMAGE` element has no `href` attribute
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// Returns the area of the image which is also covered by an object, in square points
+    pub fn overlap_area<T: Coordinates + Shape>(&self, object: &T) -> f32 {
+        self.bounding_box().overlap_area(&object.bounding_box())
+    }
+
+    /// Checks if an object overlaps the image, see [`BoundingBox::intersection`](crate::BoundingBox::intersection)
+    pub fn overlaps<T: Coordinates + Shape>(&self, object: &T) -> bool {
+        self.bounding_box().overlaps(&object.bounding_box())
+    }
+
+    /// Returns the tokens of a set that overlap the image
+    pub fn get_overlapping_tokens<'a>(&self, tokens: &Tokens<'a>) -> Tokens<'a> {
+        Tokens {
+            tokens: tokens
+                .tokens
+                .iter()
+                .filter(|token| self.overlaps(**token))
+                .copied()
+                .collect::<Vec<&'a Token>>(),
+        }
+    }
+}
+
+impl Coordinates for Image {
+    fn x(&self) -> f32 {
+        self.x
+    }
+
+    fn y(&self) -> f32 {
+        self.y
+    }
+
+    fn base(&self) -> f32 {
+        self.y + self.height
+    }
+}
+
+impl Shape for Image {
+    fn width(&self) -> f32 {
+        self.width
+    }
+
+    fn height(&self) -> f32 {
+        self.height
+    }
+
+    fn rotation(&self) -> Option<f32> {
+        None
+    }
+
+    fn angle(&self) -> Option<f32> {
+        None
+    }
+}
+
+impl Alignement for Image {}
@@ -0,0 +1,373 @@
+//! JSON export of documents.
+//!
+//! The JSON schema is versioned by [`JSON_SCHEMA_VERSION`]: fields are only added within a major version. Every object carries its id, its bounding box, a summary of its style and its children. Analyses computed with the [`Spacing`] and [`Alignement`] traits are exported on demand, see [`JsonOptions`].
+//!
+//! The `alignement` field of analyses holds the main alignement of the children (see [`Alignement::alignement`]), while the `alignements` field lists every relation within the tolerance, bottom and baseline alignements included.
+
+use super::{Block, Document, Image, Metadata, Page, Text, Token, Tokens};
+use crate::error::Error;
+use crate::traits::{Alignement, Coordinates, ObjectAlignement, Shape, Spacing, Style, Tolerance};
+
+use serde::Serialize;
+use std::io::Write;
+
+/// The version of the JSON schema written by [`Document::to_json`]
+pub const JSON_SCHEMA_VERSION: &str = "1.0";
+
+/// Default tolerance of the exported alignements, as a ratio of the font size
+const DEFAULT_ALIGNEMENT_TOLERANCE_RATIO: f32 = 0.2;
+
+/// The optional sections of the JSON export
+#[derive(Debug, Clone, Copy)]
+pub struct JsonOptions {
+    /// Exports the spacing between the children of pages, blocks and texts
+    pub spacing: bool,
+    /// Exports the alignement of the children of pages, blocks and texts
+    pub alignement: bool,
+    /// Tolerance of the `alignements` relations, as a ratio of the average font size of the page, block or text whose children are compared
+    ///
+    /// The `alignement` field always compares exact positions
+    pub alignement_tolerance_ratio: f32,
+    /// Indents the JSON output
+    pub pretty: bool,
+}
+
+impl Default for JsonOptions {
+    fn default() -> Self {
+        JsonOptions {
+            spacing: false,
+            alignement: false,
+            alignement_tolerance_ratio: DEFAULT_ALIGNEMENT_TOLERANCE_RATIO,
+            pretty: false,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonDocument<'a> {
+    version: &'static str,
+    metadata: Option<JsonMetadata<'a>>,
+    pages: Vec<JsonPage<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonMetadata<'a> {
+    pdf_filename: &'a str,
+    tool_name: &'a str,
+    tool_version: &'a str,
+    flags: Vec<&'a str>,
+    creation_date: Option<String>,
+}
+
+#[derive(Serialize)]
+struct JsonPage<'a> {
+    id: &'a str,
+    number: usize,
+    width: Option<f32>,
+    height: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analyses: Option<JsonAnalyses>,
+    blocks: Vec<JsonBlock<'a>>,
+    images: Vec<JsonImage<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonBlock<'a> {
+    id: &'a str,
+    bbox: JsonBoundingBox,
+    style: JsonStyle,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analyses: Option<JsonAnalyses>,
+    texts: Vec<JsonText<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonText<'a> {
+    id: &'a str,
+    bbox: JsonBoundingBox,
+    style: JsonStyle,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analyses: Option<JsonAnalyses>,
+    tokens: Vec<JsonToken<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonToken<'a> {
+    id: &'a str,
+    sid: Option<&'a str>,
+    value: Option<&'a str>,
+    bbox: JsonBoundingBox,
+    rotation: Option<f32>,
+    angle: Option<f32>,
+    style: JsonStyle,
+}
+
+#[derive(Serialize)]
+struct JsonImage<'a> {
+    id: &'a str,
+    href: Option<&'a str>,
+    bbox: JsonBoundingBox,
+}
+
+#[derive(Serialize)]
+struct JsonBoundingBox {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    base: f32,
+}
+
+/// Style values shared by all the tokens of an object, `null` when they differ
+#[derive(Serialize)]
+struct JsonStyle {
+    font_family: Option<String>,
+    font_size: Option<f32>,
+    avg_font_size: Option<f32>,
+    font_color: Option<String>,
+    bold: Option<bool>,
+    italic: Option<bool>,
+    monospace: Option<bool>,
+    serif: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct JsonAnalyses {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spacing: Option<JsonSpacing>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    alignement: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    alignements: Option<Vec<&'static str>>,
+}
+
+#[derive(Serialize)]
+struct JsonSpacing {
+    vertical: Vec<f32>,
+    mode_vertical: Option<f32>,
+    horizontal: Vec<f32>,
+    mode_horizontal: Option<f32>,
+}
+
+impl JsonBoundingBox {
+    fn new<T: Coordinates + Shape>(object: &T) -> JsonBoundingBox {
+        let bounding_box = object.bounding_box();
+
+        JsonBoundingBox {
+            x: bounding_box.x(),
+            y: bounding_box.y(),
+            width: bounding_box.width(),
+            height: bounding_box.height(),
+            base: object.base(),
+        }
+    }
+}
+
+impl JsonStyle {
+    fn new<T: Style>(object: &T) -> JsonStyle {
+        JsonStyle {
+            font_family: object.font_family(),
+            font_size: object.font_size(),
+            avg_font_size: object.avg_font_size(),
+            font_color: object.color().map(|color| color.to_string()),
+            bold: object.bold(),
+            italic: object.italic(),
+            monospace: object.monospace(),
+            serif: object.serif(),
+        }
+    }
+}
+
+/// Returns the name of an alignement in the JSON schema
+fn alignement_name(alignement: ObjectAlignement) -> &'static str {
+    match alignement {
+        ObjectAlignement::Alinged => "aligned",
+        ObjectAlignement::HorizontalAligned => "horizontal",
+        ObjectAlignement::HorizontalCenterAligned => "horizontal_center",
+        ObjectAlignement::VerticalLeftAligned => "vertical_left",
+        ObjectAlignement::VerticalCenterAlgined => "vertical_center",
+        ObjectAlignement::VerticalRightAlgined => "vertical_right",
+        ObjectAlignement::HorizontalBottomAligned => "horizontal_bottom",
+        ObjectAlignement::HorizontalBaselineAligned => "horizontal_baseline",
+        ObjectAlignement::NonAligned => "none",
+    }
+}
+
+/// Computes the analyses of the children of an object, whose tokens give the font size the alignement tolerance is relative to
+fn analyses<'a, T: 'a + Alignement>(
+    children: &[&'a T],
+    tokens: &Tokens,
+    options: &JsonOptions,
+) -> Option<JsonAnalyses> {
+    if !options.spacing && !options.alignement {
+        return None;
+    }
+
+    let spacing = if options.spacing {
+        let children = children.iter().copied();
+
+        Some(JsonSpacing {
+            vertical: children.clone().vertical_spacing(),
+            mode_vertical: children.clone().mode_vertical_spacing(),
+            horizontal: children.clone().horizontal_spacing(),
+            mode_horizontal: children.mode_horizontal_spacing(),
+        })
+    } else {
+        None
+    };
+
+    // Alignements of the first child with the others
+    let (alignement, alignements) = match children.split_first() {
+        Some((first, others)) if options.alignement && !others.is_empty() => {
+            let tolerance =
+                Tolerance::relative_to_font_size(tokens, options.alignement_tolerance_ratio);
+            let alignements = first.alignements(others.to_vec(), tolerance);
+            let names = alignements
+                .relations()
+                .into_iter()
+                .map(alignement_name)
+                .collect::<Vec<&'static str>>();
+
+            (
+                Some(alignement_name(first.alignement(others.to_vec()))),
+                Some(names),
+            )
+        }
+        _ => (None, None),
+    };
+
+    Some(JsonAnalyses {
+        spacing,
+        alignement,
+        alignements,
+    })
+}
+
+fn json_metadata(metadata: &Metadata) -> JsonMetadata<'_> {
+    JsonMetadata {
+        pdf_filename: metadata.pdf_filename(),
+        tool_name: metadata.tool_name(),
+        tool_version: metadata.tool_version(),
+        flags: metadata.flags(),
+        creation_date: metadata
+            .creation_date()
+            .map(|date| date.format("%Y-%m-%dT%H:%M:%S").to_string()),
+    }
+}
+
+fn json_page<'a>(page: &'a Page, options: &JsonOptions) -> JsonPage<'a> {
+    JsonPage {
+        id: &page.id,
+        number: page.number,
+        width: page.width,
+        height: page.height,
+        analyses: analyses(&page.get_blocks_borrowed(), &page.get_fsm_tokens(), options),
+        blocks: page
+            .blocks
+            .iter()
+            .map(|block| json_block(block, options))
+            .collect::<Vec<JsonBlock>>(),
+        images: page
+            .images
+            .iter()
+            .map(json_image)
+            .collect::<Vec<JsonImage>>(),
+    }
+}
+
+fn json_block<'a>(block: &'a Block, options: &JsonOptions) -> JsonBlock<'a> {
+    let texts = block.texts.iter().collect::<Vec<&Text>>();
+
+    JsonBlock {
+        id: &block.id,
+        bbox: JsonBoundingBox::new(block),
+        style: JsonStyle::new(&block.get_fsm_tokens()),
+        analyses: analyses(&texts, &block.get_fsm_tokens(), options),
+        texts: texts
+            .iter()
+            .map(|text| json_text(text, options))
+            .collect::<Vec<JsonText>>(),
+    }
+}
+
+fn json_text<'a>(text: &'a Text, options: &JsonOptions) -> JsonText<'a> {
+    let tokens = text.get_fsm_tokens();
+
+    JsonText {
+        id: &text.id,
+        bbox: JsonBoundingBox::new(text),
+        style: JsonStyle::new(&tokens),
+        analyses: analyses(&tokens.tokens, &tokens, options),
+        tokens: text
+            .tokens
+            .0
+            .iter()
+            .map(json_token)
+            .collect::<Vec<JsonToken>>(),
+    }
+}
+
+fn json_token(token: &Token) -> JsonToken<'_> {
+    JsonToken {
+        id: &token.id,
+        sid: token.sid(),
+        value: token.value.as_deref(),
+        bbox: JsonBoundingBox::new(token),
+        rotation: token.rotation(),
+        angle: token.angle(),
+        style: JsonStyle::new(token),
+    }
+}
+
+fn json_image(image: &Image) -> JsonImage<'_> {
+    JsonImage {
+        id: image.id(),
+        href: image.href(),
+        bbox: JsonBoundingBox::new(image),
+    }
+}
+
+impl Document {
+    /// Writes a document as JSON
+    ///
+    /// ```no_run
+    /// # use pdf_shape::*;
+    /// # fn example() -> Result<(), Error> {
+    /// let document = Document::from_path("./examples/xml_sample/sample_1.xml")?;
+    /// let options = JsonOptions {
+    ///     spacing: true,
+    ///     ..JsonOptions::default()
+    /// };
+    ///
+    /// document.write_json(std::fs::File::create("./sample_1.json")?, &options)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_json<W: Write>(&self, writer: W, options: &JsonOptions) -> Result<(), Error> {
+        let document = JsonDocument {
+            version: JSON_SCHEMA_VERSION,
+            metadata: self.metadata.as_ref().map(json_metadata),
+            pages: self
+                .pages
+                .iter()
+                .map(|page| json_page(page, options))
+                .collect::<Vec<JsonPage>>(),
+        };
+
+        if options.pretty {
+            serde_json::to_writer_pretty(writer, &document)?;
+        } else {
+            serde_json::to_writer(writer, &document)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns a document as JSON
+    pub fn to_json(&self, options: &JsonOptions) -> Result<String, Error> {
+        let mut json = Vec::new();
+        self.write_json(&mut json, options)?;
+
+        Ok(String::from_utf8_lossy(&json).into_owned())
+    }
+}
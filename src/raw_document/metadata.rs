@@ -0,0 +1,133 @@
+//! The `METADATA` section of a pdf2xml file.
+
+use crate::error::Error;
+
+use chrono::NaiveDateTime;
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::Writer;
+use serde::Deserialize;
+use std::io::Write;
+
+/// A struct representing the metadata of a pdf2xml file
+///
+/// Every element and attribute of the `METADATA` section is optional: missing values are reported as empty.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Metadata {
+    #[serde(alias = "PDFFILENAME", default)]
+    pdf_filename: String,
+    #[serde(alias = "PROCESS", default)]
+    process: Process,
+}
+
+/// A struct representing the process which produced a pdf2xml file
+#[derive(Debug, Deserialize, Clone, Default)]
+struct Process {
+    #[serde(default)]
+    name: String,
+    cmd: Option<String>,
+    #[serde(alias = "VERSION")]
+    version: Option<Version>,
+    #[serde(alias = "CREATIONDATE")]
+    creation_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct Version {
+    #[serde(default)]
+    value: String,
+}
+
+impl Metadata {
+    /// Returns the file name of the source PDF
+    pub fn pdf_filename(&self) -> &str {
+        self.pdf_filename.trim()
+    }
+
+    /// Returns the name of the tool which produced the file (e.g. `pdftoxml`)
+    pub fn tool_name(&self) -> &str {
+        &self.process.name
+    }
+
+    /// Returns the version of the tool which produced the file
+    pub fn tool_version(&self) -> &str {
+        self.process
+            .version
+            .as_ref()
+            .map_or("", |version| version.value.as_str())
+    }
+
+    /// Returns the command line flags passed to the tool (e.g. `["-noImage", "-blocks"]`)
+    pub fn flags(&self) -> Vec<&str> {
+        self.process
+            .cmd
+            .as_deref()
+            .unwrap_or_default()
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+    }
+
+    /// Checks if a command line flag was passed to the tool
+    ///
+    /// ```no_run
+    /// # use pdf_shape::*;
+    /// # fn example(document: &Document) {
+    /// if let Some(metadata) = document.get_metadata() {
+    ///     assert!(metadata.has_flag("-blocks"));
+    /// }
+    /// # }
+    /// ```
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags().contains(&flag)
+    }
+
+    /// Returns the creation date of the file
+    ///
+    /// ⚠️ This method returns `None` if the date is missing or doesn't follow the `Tue Sep 29 14:20:29 2020` format
+    pub fn creation_date(&self) -> Option<NaiveDateTime> {
+        // Days of the month may be padded with spaces
+        let date = self
+            .process
+            .creation_date
+            .as_deref()?
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ");
+
+        NaiveDateTime::parse_from_str(&date, "%a %b %d %H:%M:%S %Y").ok()
+    }
+
+    /// Writes the `METADATA` element of a pdf2xml file
+    pub(super) fn write_xml<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), Error> {
+        writer.write_event(Event::Start(BytesStart::borrowed_name(b"METADATA")))?;
+
+        writer.write_event(Event::Start(BytesStart::borrowed_name(b"PDFFILENAME")))?;
+        writer.write_event(Event::Text(BytesText::from_plain_str(&self.pdf_filename)))?;
+        writer.write_event(Event::End(BytesEnd::borrowed(b"PDFFILENAME")))?;
+
+        let mut process = BytesStart::borrowed_name(b"PROCESS");
+        process.push_attribute(("name", self.process.name.as_str()));
+        if let Some(cmd) = &self.process.cmd {
+            process.push_attribute(("cmd", cmd.as_str()));
+        }
+        writer.write_event(Event::Start(process))?;
+
+        if let Some(version) = &self.process.version {
+            let mut element = BytesStart::borrowed_name(b"VERSION");
+            element.push_attribute(("value", version.value.as_str()));
+            writer.write_event(Event::Start(element))?;
+            writer.write_event(Event::Empty(BytesStart::borrowed_name(b"COMMENT")))?;
+            writer.write_event(Event::End(BytesEnd::borrowed(b"VERSION")))?;
+        }
+
+        if let Some(creation_date) = &self.process.creation_date {
+            writer.write_event(Event::Start(BytesStart::borrowed_name(b"CREATIONDATE")))?;
+            writer.write_event(Event::Text(BytesText::from_plain_str(creation_date)))?;
+            writer.write_event(Event::End(BytesEnd::borrowed(b"CREATIONDATE")))?;
+        }
+
+        writer.write_event(Event::End(BytesEnd::borrowed(b"PROCESS")))?;
+        writer.write_event(Event::End(BytesEnd::borrowed(b"METADATA")))?;
+
+        Ok(())
+    }
+}
@@ -1,11 +1,46 @@
 //! This module is used to deserialize XML files produced by [pdf2xml](https://github.com/kermitt2/pdf2xml).
 //!
-//! You should use the `-blocks` arg of pdf2xml to produce files that can be deserialize with rythes appropriate XML files.
-
+//! Files produced with or without the `-blocks` arg of pdf2xml can be deserialized. Without blocks, each `TEXT` element is wrapped into its own block so that the document tree is always the same.
+
+mod alto;
+mod annotation;
+mod attributes;
+mod color;
+mod diagnosis;
+mod font;
+mod hocr;
+mod image;
+mod json;
+mod metadata;
+mod outline;
+mod pdftohtml;
+mod serialize;
+mod stream;
+mod vector;
+
+pub use annotation::{Link, LinkTarget};
+pub use color::Color;
+pub use font::{Font, FontStyle, FontWeight};
+pub use image::Image;
+pub use json::{JsonOptions, JSON_SCHEMA_VERSION};
+pub use metadata::Metadata;
+pub use outline::{Outline, OutlineEntry};
+pub use stream::PageReader;
+pub use vector::{LineSegment, Path, PathSegment, Rect};
+
+use crate::error::Error;
+use crate::layout::{Column, Line, Paragraph};
+use crate::spatial::SpatialIndex;
 use crate::traits::{Alignement, Coordinates, Shape, Style};
 
+use quick_xml::DeError;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path as FilePath;
+use std::str::FromStr;
 
 /// A struct representing a set of tokens
 #[derive(Debug, Clone, Deserialize)]
@@ -43,26 +78,221 @@ impl DeserizalizationTokens {
 
 /// A struct representing the XML document created by xml2pdf
 #[derive(Debug, Deserialize, Clone)]
+#[serde(from = "RawDocument")]
 pub struct Document {
-    #[serde(alias = "BLOCK")]
-    blocks: Vec<Block>,
+    metadata: Option<Metadata>,
+    pages: Vec<Page>,
+    outline: Option<Outline>,
+}
+
+/// The root element of a pdf2xml file.
+///
+/// pdf2xml wraps blocks and images into `PAGE` elements, but blocks (or texts, for files produced without the `-blocks` arg) and images may also be found straight under the root element.
+#[derive(Debug, Deserialize)]
+struct RawDocument {
+    #[serde(rename = "$value", default)]
+    elements: Vec<Element>,
+}
+
+/// A child of the root element or of a `PAGE` element of a pdf2xml file
+///
+/// Children are read as a single list so that elements of different kinds can be interleaved.
+#[derive(Debug, Deserialize)]
+enum Element {
+    #[serde(rename = "METADATA")]
+    Metadata(Metadata),
+    #[serde(rename = "PAGE")]
+    Page(Page),
+    #[serde(rename = "BLOCK")]
+    Block(Block),
+    #[serde(rename = "TEXT")]
+    Text(Text),
+    #[serde(rename = "IMAGE")]
+    Image(Image),
+    #[serde(other)]
+    Other,
+}
+
+impl From<RawDocument> for Document {
+    fn from(raw_document: RawDocument) -> Self {
+        let mut metadata = None;
+        let mut pages: Vec<Page> = Vec::new();
+        let mut images: Vec<Image> = Vec::new();
+
+        for element in raw_document.elements {
+            match element {
+                Element::Metadata(document_metadata) => metadata = Some(document_metadata),
+                Element::Page(page) => pages.push(page),
+                Element::Block(block) => push_block(&mut pages, block),
+                Element::Text(text) => push_block(&mut pages, Block::from_text(text)),
+                Element::Image(image) => images.push(image),
+                Element::Other => (),
+            }
+        }
+
+        for image in images {
+            let number = Page::number_from_id(image.id())
+                .or_else(|| pages.last().map(|page| page.number))
+                .unwrap_or(1);
+
+            match pages.iter_mut().find(|page| page.number == number) {
+                Some(page) => page.images.push(image),
+                None => {
+                    let mut page = Page::without_geometry(number, Vec::new());
+                    page.images.push(image);
+                    pages.push(page);
+                }
+            }
+        }
+
+        Document {
+            metadata,
+            pages,
+            outline: None,
+        }
+    }
+}
+
+/// Adds a block found straight under the root element to its page
+///
+/// Blocks are grouped by page using their id (e.g. `p1_b12`)
+fn push_block(pages: &mut Vec<Page>, block: Block) {
+    let number = Page::number_from_id(&block.id)
+        .or_else(|| pages.last().map(|page| page.number))
+        .unwrap_or(1);
+
+    match pages.last_mut() {
+        Some(page) if page.number == number => page.blocks.push(block),
+        _ => pages.push(Page::without_geometry(number, vec![block])),
+    }
+}
+
+impl FromStr for Document {
+    type Err = Error;
+
+    /// Parses a document from the content of a pdf2xml file
+    fn from_str(xml: &str) -> Result<Self, Self::Err> {
+        deserialize(xml)
+    }
+}
+
+/// Returns the element in which a child element is found, or an error if the child element is misplaced
+fn parent<'a, T>(
+    parent: Option<&'a mut T>,
+    element: &'static str,
+    expected_parent: &'static str,
+) -> Result<&'a mut T, Error> {
+    parent.ok_or_else(|| {
+        Error::Deserialization(DeError::Custom(format!(
+            "{} element found outside of a {} element",
+            element, expected_parent
+        )))
+    })
+}
+
+/// Deserializes a pdf2xml document or a fragment of it
+fn deserialize<T: DeserializeOwned>(xml: &str) -> Result<T, Error> {
+    quick_xml::de::from_str(xml)
+        .map_err(|error| diagnosis::diagnose(xml).unwrap_or_else(|| Error::from(error)))
 }
 
 impl Document {
+    /// Reads and parses a pdf2xml file
+    pub fn from_path<P: AsRef<FilePath>>(path: P) -> Result<Document, Error> {
+        Document::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Reads and parses a pdf2xml document from a reader
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Document, Error> {
+        let mut xml = String::new();
+        reader.read_to_string(&mut xml)?;
+
+        Document::from_str(&xml)
+    }
+
+    /// Returns the metadata of a document
+    ///
+    /// ⚠️ This method returns `None` if the document has no `METADATA` element
+    pub fn get_metadata(&self) -> Option<&Metadata> {
+        self.metadata.as_ref()
+    }
+
+    /// Returns the outline (bookmarks) of a document
+    ///
+    /// ⚠️ This method returns `None` until the outline file of the document has been loaded, see [`Document::load_outline_path`]
+    pub fn get_outline(&self) -> Option<&Outline> {
+        self.outline.as_ref()
+    }
+
+    /// Returns the page of a document with the given number
+    pub fn get_page(&self, number: usize) -> Option<&Page> {
+        self.pages.iter().find(|page| page.number == number)
+    }
+
+    /// Returns all the pages of a document
+    pub fn get_pages(&self) -> Vec<Page> {
+        self.pages.clone()
+    }
+
+    /// Returns all the pages of a document in a borrowed manner
+    pub fn get_pages_borrowed(&self) -> Vec<&Page> {
+        self.pages.iter().collect::<Vec<&Page>>()
+    }
+
     /// Returns all the blocks elements of a document
     pub fn get_blocks(&self) -> Vec<Block> {
-        self.blocks.clone()
+        self.pages
+            .iter()
+            .flat_map(|page| page.blocks.clone())
+            .collect::<Vec<Block>>()
     }
 
     /// Returns all the blocks elements of a document in a borrowed manner
     pub fn get_blocks_borrowed(&self) -> Vec<&Block> {
-        let mut borowed_block = vec![];
+        self.pages
+            .iter()
+            .flat_map(|page| &page.blocks)
+            .collect::<Vec<&Block>>()
+    }
 
-        for block in &self.blocks {
-            borowed_block.push(block)
-        }
+    /// Returns all the image elements of a document
+    pub fn get_images(&self) -> Vec<Image> {
+        self.pages
+            .iter()
+            .flat_map(|page| page.images.clone())
+            .collect::<Vec<Image>>()
+    }
+
+    /// Returns all the image elements of a document in a borrowed manner
+    pub fn get_images_borrowed(&self) -> Vec<&Image> {
+        self.pages
+            .iter()
+            .flat_map(|page| &page.images)
+            .collect::<Vec<&Image>>()
+    }
+
+    /// Returns all the paths of the vector graphics of a document
+    pub fn get_paths(&self) -> Vec<Path> {
+        self.pages
+            .iter()
+            .flat_map(|page| page.paths.clone())
+            .collect::<Vec<Path>>()
+    }
 
-        borowed_block
+    /// Returns all the paths of the vector graphics of a document in a borrowed manner
+    pub fn get_paths_borrowed(&self) -> Vec<&Path> {
+        self.pages
+            .iter()
+            .flat_map(|page| &page.paths)
+            .collect::<Vec<&Path>>()
+    }
+
+    /// Returns all the links of a document
+    pub fn get_links(&self) -> Vec<Link> {
+        self.pages
+            .iter()
+            .flat_map(|page| page.links.clone())
+            .collect::<Vec<Link>>()
     }
 
     /// Returns all the text elements of a document
@@ -91,7 +321,8 @@ impl Document {
         )
     }
 
-    pub fn get_fsm_tokens(&self) -> Tokens {
+    /// Returns all the tokens elements of a document in a borrowed manner
+    pub fn get_fsm_tokens(&self) -> Tokens<'_> {
         Tokens {
             tokens: self
                 .get_texts_borrowed()
@@ -100,6 +331,304 @@ impl Document {
                 .collect::<Vec<&Token>>(),
         }
     }
+
+    /// Returns all the lines of a document
+    ///
+    /// Lines are detected page by page, see [`Line::detect`]
+    pub fn get_lines(&self) -> Vec<Line<'_>> {
+        self.pages
+            .iter()
+            .flat_map(|page| page.get_lines())
+            .collect::<Vec<Line>>()
+    }
+
+    /// Returns all the paragraphs of a document
+    ///
+    /// Paragraphs are detected page by page, see [`Paragraph::detect`]
+    pub fn get_paragraphs(&self) -> Vec<Paragraph<'_>> {
+        self.pages
+            .iter()
+            .flat_map(|page| page.get_paragraphs())
+            .collect::<Vec<Paragraph>>()
+    }
+
+    /// Returns all the columns of a document
+    ///
+    /// Columns are detected page by page, see [`Column::detect`]
+    pub fn get_columns(&self) -> Vec<Column<'_>> {
+        self.pages
+            .iter()
+            .flat_map(|page| page.get_columns())
+            .collect::<Vec<Column>>()
+    }
+
+    /// Returns a spatial index over the tokens of each page of a document, in page order
+    pub fn get_token_indexes(&self) -> Vec<SpatialIndex<'_, Token>> {
+        self.pages
+            .iter()
+            .map(|page| page.get_token_index())
+            .collect::<Vec<SpatialIndex<Token>>>()
+    }
+
+    /// Returns a spatial index over the text elements of each page of a document, in page order
+    pub fn get_text_indexes(&self) -> Vec<SpatialIndex<'_, Text>> {
+        self.pages
+            .iter()
+            .map(|page| page.get_text_index())
+            .collect::<Vec<SpatialIndex<Text>>>()
+    }
+
+    /// Returns a spatial index over the blocks of each page of a document, in page order
+    pub fn get_block_indexes(&self) -> Vec<SpatialIndex<'_, Block>> {
+        self.pages
+            .iter()
+            .map(|page| page.get_block_index())
+            .collect::<Vec<SpatialIndex<Block>>>()
+    }
+}
+
+/// A struct representing a page. Page holds block elements
+#[derive(Debug, Deserialize, Clone)]
+#[serde(from = "RawPage")]
+pub struct Page {
+    id: String,
+    number: usize,
+    width: Option<f32>,
+    height: Option<f32>,
+    blocks: Vec<Block>,
+    images: Vec<Image>,
+    paths: Vec<Path>,
+    links: Vec<Link>,
+}
+
+/// A `PAGE` element of a pdf2xml file.
+///
+/// Files produced without the `-blocks` arg of pdf2xml hold `TEXT` elements straight under the `PAGE` elements.
+#[derive(Debug, Deserialize)]
+struct RawPage {
+    id: String,
+    number: usize,
+    width: Option<f32>,
+    height: Option<f32>,
+    #[serde(rename = "$value", default)]
+    elements: Vec<Element>,
+}
+
+impl From<RawPage> for Page {
+    fn from(raw_page: RawPage) -> Self {
+        let mut blocks = Vec::new();
+        let mut images = Vec::new();
+
+        for element in raw_page.elements {
+            match element {
+                Element::Block(block) => blocks.push(block),
+                Element::Text(text) => blocks.push(Block::from_text(text)),
+                Element::Image(image) => images.push(image),
+                _ => (),
+            }
+        }
+
+        Page {
+            id: raw_page.id,
+            number: raw_page.number,
+            width: raw_page.width,
+            height: raw_page.height,
+            blocks,
+            images,
+            paths: Vec::new(),
+            links: Vec::new(),
+        }
+    }
+}
+
+impl Page {
+    /// Creates a page holding blocks found outside of any `PAGE` element
+    fn without_geometry(number: usize, blocks: Vec<Block>) -> Page {
+        Page {
+            id: format!("p{}", number),
+            number,
+            width: None,
+            height: None,
+            blocks,
+            images: Vec::new(),
+            paths: Vec::new(),
+            links: Vec::new(),
+        }
+    }
+
+    /// Extracts the page number from the id of an element (e.g. `p1_b12`)
+    fn number_from_id(id: &str) -> Option<usize> {
+        id.strip_prefix('p')?.split('_').next()?.parse().ok()
+    }
+
+    /// Returns the id of the page
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the number of the page
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    /// Returns the width of the page
+    ///
+    /// ⚠️ This method returns `None` if the page was not described by a `PAGE` element
+    pub fn width(&self) -> Option<f32> {
+        self.width
+    }
+
+    /// Returns the height of the page
+    ///
+    /// ⚠️ This method returns `None` if the page was not described by a `PAGE` element
+    pub fn height(&self) -> Option<f32> {
+        self.height
+    }
+
+    /// Returns all the blocks elements of a page
+    pub fn get_blocks(&self) -> Vec<Block> {
+        self.blocks.clone()
+    }
+
+    /// Returns all the blocks elements of a page in a borrowed manner
+    pub fn get_blocks_borrowed(&self) -> Vec<&Block> {
+        self.blocks.iter().collect::<Vec<&Block>>()
+    }
+
+    /// Returns all the image elements of a page
+    pub fn get_images(&self) -> Vec<Image> {
+        self.images.clone()
+    }
+
+    /// Returns all the image elements of a page in a borrowed manner
+    pub fn get_images_borrowed(&self) -> Vec<&Image> {
+        self.images.iter().collect::<Vec<&Image>>()
+    }
+
+    /// Returns all the paths of the vector graphics of a page
+    ///
+    /// Paths are only available once the `.vec` file of the document has been loaded, see [`Document::load_vec_path`]
+    pub fn get_paths(&self) -> Vec<Path> {
+        self.paths.clone()
+    }
+
+    /// Returns all the paths of the vector graphics of a page in a borrowed manner
+    pub fn get_paths_borrowed(&self) -> Vec<&Path> {
+        self.paths.iter().collect::<Vec<&Path>>()
+    }
+
+    /// Returns all the rectangles drawn on a page
+    pub fn get_rects(&self) -> Vec<Rect> {
+        self.paths
+            .iter()
+            .filter_map(|path| path.as_rect())
+            .collect::<Vec<Rect>>()
+    }
+
+    /// Returns all the straight lines drawn on a page
+    pub fn get_line_segments(&self) -> Vec<LineSegment> {
+        self.paths
+            .iter()
+            .flat_map(|path| path.line_segments())
+            .collect::<Vec<LineSegment>>()
+    }
+
+    /// Returns all the links of a page
+    ///
+    /// Links are only available once the annotations file of the document has been loaded, see [`Document::load_annotations_path`]
+    pub fn get_links(&self) -> Vec<Link> {
+        self.links.clone()
+    }
+
+    /// Returns all the links of a page in a borrowed manner
+    pub fn get_links_borrowed(&self) -> Vec<&Link> {
+        self.links.iter().collect::<Vec<&Link>>()
+    }
+
+    /// Returns all the tokens of a page which are not covered by an image
+    pub fn get_fsm_tokens_outside_images(&self) -> Tokens<'_> {
+        Tokens {
+            tokens: self
+                .get_fsm_tokens()
+                .tokens
+                .into_iter()
+                .filter(|token| !self.images.iter().any(|image| image.overlaps(*token)))
+                .collect::<Vec<&Token>>(),
+        }
+    }
+
+    /// Returns all the text elements of a page
+    pub fn get_texts(&self) -> Vec<Text> {
+        self.blocks
+            .iter()
+            .flat_map(|block| block.texts.clone())
+            .collect::<Vec<Text>>()
+    }
+
+    /// Returns all the text elements of a page in a borrowed manner
+    pub fn get_texts_borrowed(&self) -> Vec<&Text> {
+        self.blocks
+            .iter()
+            .flat_map(|block| &block.texts)
+            .collect::<Vec<&Text>>()
+    }
+
+    /// Returns all the tokens elements of a page
+    pub fn get_tokens(&self) -> DeserizalizationTokens {
+        DeserizalizationTokens(
+            self.get_texts()
+                .iter()
+                .flat_map(|text| text.tokens.0.clone())
+                .collect::<Vec<Token>>(),
+        )
+    }
+
+    /// Returns all the tokens elements of a page in a borrowed manner
+    pub fn get_fsm_tokens(&self) -> Tokens<'_> {
+        Tokens {
+            tokens: self
+                .get_texts_borrowed()
+                .iter()
+                .flat_map(|text| &text.tokens.0)
+                .collect::<Vec<&Token>>(),
+        }
+    }
+
+    /// Returns all the lines of a page
+    ///
+    /// Lines are detected over all the tokens of the page, see [`Line::detect`]
+    pub fn get_lines(&self) -> Vec<Line<'_>> {
+        Line::detect(&self.get_fsm_tokens())
+    }
+
+    /// Returns all the paragraphs of a page
+    ///
+    /// Paragraphs are detected over all the lines of the page, see [`Paragraph::detect`]
+    pub fn get_paragraphs(&self) -> Vec<Paragraph<'_>> {
+        Paragraph::detect(self.get_lines())
+    }
+
+    /// Returns all the columns of a page
+    ///
+    /// Columns are detected over all the tokens of the page, see [`Column::detect`]
+    pub fn get_columns(&self) -> Vec<Column<'_>> {
+        Column::detect(&self.get_fsm_tokens())
+    }
+
+    /// Returns a spatial index over the tokens of a page
+    pub fn get_token_index(&self) -> SpatialIndex<'_, Token> {
+        SpatialIndex::new(self.get_fsm_tokens())
+    }
+
+    /// Returns a spatial index over the text elements of a page
+    pub fn get_text_index(&self) -> SpatialIndex<'_, Text> {
+        SpatialIndex::new(self.blocks.iter().flat_map(|block| &block.texts))
+    }
+
+    /// Returns a spatial index over the blocks of a page
+    pub fn get_block_index(&self) -> SpatialIndex<'_, Block> {
+        SpatialIndex::new(&self.blocks)
+    }
 }
 
 /// A struct representing a block. Block holds text elements
@@ -137,6 +666,9 @@ pub struct Token {
     sid: Option<String>,
     id: String,
     font_name: Option<String>,
+    fixed_width: Option<bool>,
+    serif: Option<bool>,
+    symbolic: Option<bool>,
     bold: bool,
     italic: bool,
     font_color: String,
@@ -150,6 +682,161 @@ pub struct Token {
     height: f32,
     #[serde(rename = "$value")]
     pub value: Option<String>,
+    #[serde(skip)]
+    links: Vec<Link>,
+}
+
+impl Block {
+    /// Wraps a text element found outside of any `BLOCK` element into its own block
+    fn from_text(text: Text) -> Block {
+        // p1_t13 → p1_b13, ids without `_t` are kept
+        let id = match text.id.rfind("_t") {
+            Some(index) => format!("{}_b{}", &text.id[..index], &text.id[index + 2..]),
+            None => text.id.clone(),
+        };
+
+        Block {
+            id,
+            x: text.x,
+            y: text.y,
+            width: Some(text.width),
+            height: Some(text.height),
+            texts: vec![text],
+        }
+    }
+
+    /// Returns the id of the block
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns all the tokens elements of the block in a borrowed manner
+    pub fn get_fsm_tokens(&self) -> Tokens<'_> {
+        Tokens {
+            tokens: self
+                .texts
+                .iter()
+                .flat_map(|text| &text.tokens.0)
+                .collect::<Vec<&Token>>(),
+        }
+    }
+}
+
+impl Coordinates for Block {
+    fn x(&self) -> f32 {
+        self.x
+    }
+
+    fn y(&self) -> f32 {
+        self.y
+    }
+
+    fn base(&self) -> f32 {
+        self.get_fsm_tokens().base()
+    }
+}
+
+impl Shape for Block {
+    fn width(&self) -> f32 {
+        self.width.unwrap_or_else(|| self.get_fsm_tokens().width())
+    }
+
+    fn height(&self) -> f32 {
+        self.height
+            .unwrap_or_else(|| self.get_fsm_tokens().height())
+    }
+
+    fn rotation(&self) -> Option<f32> {
+        None
+    }
+
+    fn angle(&self) -> Option<f32> {
+        None
+    }
+}
+
+impl Alignement for Block {}
+
+impl Text {
+    /// Returns the id of the text element
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns all the tokens elements of the text element in a borrowed manner
+    pub fn get_fsm_tokens(&self) -> Tokens<'_> {
+        Tokens {
+            tokens: self.tokens.0.iter().collect::<Vec<&Token>>(),
+        }
+    }
+}
+
+impl Coordinates for Text {
+    fn x(&self) -> f32 {
+        self.x
+    }
+
+    fn y(&self) -> f32 {
+        self.y
+    }
+
+    fn base(&self) -> f32 {
+        self.get_fsm_tokens().base()
+    }
+}
+
+impl Shape for Text {
+    fn width(&self) -> f32 {
+        self.width
+    }
+
+    fn height(&self) -> f32 {
+        self.height
+    }
+
+    fn rotation(&self) -> Option<f32> {
+        None
+    }
+
+    fn angle(&self) -> Option<f32> {
+        None
+    }
+}
+
+impl Alignement for Text {}
+
+impl Token {
+    /// Returns the id of the token
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the sid of the token
+    pub fn sid(&self) -> Option<&str> {
+        self.sid.as_deref()
+    }
+
+    /// Returns the raw font name of the token (e.g. `cambria,bold`)
+    pub fn font_name(&self) -> Option<&str> {
+        self.font_name.as_deref()
+    }
+
+    /// Returns the font of the token, parsed from its font name
+    pub fn font(&self) -> Option<Font> {
+        self.font_name.as_deref().map(Font::parse)
+    }
+
+    /// Returns the links covering the token
+    pub fn links(&self) -> &[Link] {
+        &self.links
+    }
+
+    /// Checks if the font of the token is symbolic
+    ///
+    /// ⚠️ This method returns `None` if the information is not available in the source format
+    pub fn symbolic(&self) -> Option<bool> {
+        self.symbolic
+    }
 }
 
 impl Coordinates for Token {
@@ -199,74 +886,87 @@ impl Style for Token {
     fn italic(&self) -> Option<bool> {
         Some(self.italic)
     }
+
+    fn color(&self) -> Option<Color> {
+        Color::from_hex(&self.font_color)
+    }
+
+    fn monospace(&self) -> Option<bool> {
+        self.fixed_width
+    }
+
+    fn serif(&self) -> Option<bool> {
+        self.serif
+    }
+
+    fn font_family(&self) -> Option<String> {
+        self.font().map(|font| font.family().to_string())
+    }
 }
 
 impl Alignement for Token {}
 
+/// A struct representing a borrowed set of tokens
 #[derive(Debug, Clone)]
 pub struct Tokens<'a> {
     pub tokens: Vec<&'a Token>,
 }
 
 impl<'a> Tokens<'a> {
-    fn first_token(&self) -> Option<&Token> {
-        self.tokens.first().map(|t| *t)
+    /// Returns the first token of the set
+    pub fn first_token(&self) -> Option<&Token> {
+        self.tokens.first().copied()
     }
 
-    fn last_token(&self) -> Option<&Token> {
-        self.tokens.last().map(|t| *t)
+    /// Returns the last token of the set
+    pub fn last_token(&self) -> Option<&Token> {
+        self.tokens.last().copied()
     }
 
-    fn widest_token(&self) -> Option<&Token> {
-        let mut tokens = self.tokens.clone();
-
-        tokens.sort_by(|a, b| a.width.partial_cmp(&b.width).unwrap());
-        tokens.last().map(|t| *t)
+    /// Returns the widest token of the set
+    pub fn widest_token(&self) -> Option<&Token> {
+        self.tokens
+            .iter()
+            .max_by(|a, b| a.width.total_cmp(&b.width))
+            .copied()
     }
-}
-
-impl<'a> IntoIterator for Tokens<'a> {
-    type Item = &'a Token;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.tokens.into_iter()
-    }
-}
+    /// Returns the links covering the tokens of the set, each link being returned once
+    pub fn links(&self) -> Vec<&'a Link> {
+        let mut links: Vec<&'a Link> = Vec::new();
 
-impl<'a> Coordinates for Tokens<'a> {
-    fn x(&self) -> f32 {
-        if let Some(first_token) = self.first_token() {
-            first_token.x
-        } else {
-            0.0
+        for link in self.tokens.iter().flat_map(|token| &token.links) {
+            if !links.contains(&link) {
+                links.push(link);
+            }
         }
-    }
 
-    fn y(&self) -> f32 {
-        if let Some(first_token) = self.first_token() {
-            first_token.y
-        } else {
-            0.0
-        }
+        links
     }
 
-    /// Takes the lowest base among a collection of token
-    fn base(&self) -> f32 {
-        use std::cmp::Ordering::Equal;
+    /// Returns the font colours of the set with their number of tokens, the most used colour first
+    ///
+    /// Tokens whose colour is unknown are ignored
+    pub fn color_histogram(&self) -> Vec<(Color, usize)> {
+        let mut histogram: Vec<(Color, usize)> = Vec::new();
+
+        for color in self.tokens.iter().filter_map(|token| token.color()) {
+            match histogram.iter_mut().find(|(c, _)| *c == color) {
+                Some((_, count)) => *count += 1,
+                None => histogram.push((color, 1)),
+            }
+        }
 
-        let mut bases = self
-            .tokens
-            .iter()
-            .map(|token| token.base())
-            .collect::<Vec<f32>>();
+        histogram.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        histogram
+    }
+}
 
-        bases.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
+impl<'a> IntoIterator for Tokens<'a> {
+    type Item = &'a Token;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
 
-        if let Some(base) = bases.first() {
-            *base
-        } else {
-            0.0
-        }
+    fn into_iter(self) -> Self::IntoIter {
+        self.tokens.into_iter()
     }
 }
@@ -0,0 +1,296 @@
+//! Loading of the outline (bookmarks) file written by pdf2xml next to the XML file.
+//!
+//! The outline is a hierarchy of `TOCITEMLIST` elements holding `ITEM` elements, each item having a title (`STRING`) and a target (`LINK page top`). Nested lists are the children of the item they follow (or of the item they are found in). The outline is kept in an [`indextree`] arena.
+
+use super::attributes::Attributes;
+use super::{Document, Page};
+use crate::error::Error;
+
+use indextree::{Arena, NodeId};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path as FilePath;
+
+/// A struct representing an entry (bookmark) of the outline of a document
+#[derive(Debug, Clone)]
+pub struct OutlineEntry {
+    title: String,
+    page: Option<usize>,
+    top: Option<f32>,
+    token_ids: Vec<String>,
+}
+
+impl OutlineEntry {
+    /// Returns the title of the entry
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the number of the page targeted by the entry
+    ///
+    /// ⚠️ This method returns `None` if the entry has no `LINK` element
+    pub fn page(&self) -> Option<usize> {
+        self.page
+    }
+
+    /// Returns the vertical position targeted by the entry
+    pub fn top(&self) -> Option<f32> {
+        self.top
+    }
+
+    /// Returns the ids of the tokens of the targeted page whose text matches the title of the entry
+    ///
+    /// ⚠️ This method returns an empty vector if no heading matching the title was found
+    pub fn token_ids(&self) -> Vec<&str> {
+        self.token_ids.iter().map(String::as_str).collect()
+    }
+}
+
+/// A struct representing the outline of a document
+#[derive(Debug, Clone)]
+pub struct Outline {
+    arena: Arena<OutlineEntry>,
+    roots: Vec<NodeId>,
+}
+
+impl Outline {
+    /// Returns the arena holding the entries of the outline
+    pub fn arena(&self) -> &Arena<OutlineEntry> {
+        &self.arena
+    }
+
+    /// Returns the top level entries of the outline
+    pub fn roots(&self) -> &[NodeId] {
+        &self.roots
+    }
+
+    /// Returns an entry of the outline
+    pub fn get(&self, node: NodeId) -> Option<&OutlineEntry> {
+        self.arena.get(node).map(|node| node.get())
+    }
+
+    /// Returns the children of an entry
+    pub fn children(&self, node: NodeId) -> Vec<NodeId> {
+        node.children(&self.arena).collect::<Vec<NodeId>>()
+    }
+
+    /// Returns the parent of an entry
+    ///
+    /// ⚠️ This method returns `None` for top level entries
+    pub fn parent(&self, node: NodeId) -> Option<NodeId> {
+        self.arena.get(node).and_then(|node| node.parent())
+    }
+
+    /// Returns all the entries of the outline in reading order, with their depth (0 for top level entries)
+    pub fn entries(&self) -> Vec<(usize, &OutlineEntry)> {
+        self.roots
+            .iter()
+            .flat_map(|root| root.descendants(&self.arena))
+            .filter_map(|node| {
+                let depth = node.ancestors(&self.arena).count() - 1;
+                self.get(node).map(|entry| (depth, entry))
+            })
+            .collect::<Vec<(usize, &OutlineEntry)>>()
+    }
+}
+
+/// Splits a text into lowercased words made of alphanumeric characters
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<String>>()
+}
+
+/// Returns the ids of the tokens of a page whose words match a title
+///
+/// When the title is found several times, the occurrence closest to the targeted position is kept
+fn find_heading(page: &Page, title: &str, top: Option<f32>) -> Vec<String> {
+    let title = words(title);
+
+    if title.is_empty() {
+        return Vec::new();
+    }
+
+    // Words of the page with the index of the token they belong to
+    let tokens = page.get_fsm_tokens().tokens;
+    let page_words = tokens
+        .iter()
+        .enumerate()
+        .flat_map(|(index, token)| {
+            words(token.value.as_deref().unwrap_or_default())
+                .into_iter()
+                .map(move |word| (index, word))
+        })
+        .collect::<Vec<(usize, String)>>();
+
+    let mut best: Option<(f32, Vec<String>)> = None;
+
+    for window in page_words.windows(title.len()) {
+        if !window
+            .iter()
+            .zip(&title)
+            .all(|((_, word), title_word)| word == title_word)
+        {
+            continue;
+        }
+
+        let mut indexes = window
+            .iter()
+            .map(|(index, _)| *index)
+            .collect::<Vec<usize>>();
+        indexes.dedup();
+
+        let distance = match top {
+            Some(top) => (tokens[indexes[0]].y - top).abs(),
+            None => 0.0,
+        };
+
+        if best.as_ref().map_or(true, |(d, _)| distance < *d) {
+            let ids = indexes
+                .iter()
+                .map(|index| tokens[*index].id().to_string())
+                .collect::<Vec<String>>();
+
+            best = Some((distance, ids));
+        }
+    }
+
+    best.map(|(_, ids)| ids).unwrap_or_default()
+}
+
+impl Document {
+    /// Reads an outline file written by pdf2xml and attaches it to the document
+    pub fn load_outline_path<P: AsRef<FilePath>>(&mut self, path: P) -> Result<(), Error> {
+        self.load_outline_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Parses the content of an outline file and attaches it to the document
+    pub fn load_outline_str(&mut self, xml: &str) -> Result<(), Error> {
+        self.load_outline_reader(xml.as_bytes())
+    }
+
+    /// Reads an outline file from a buffered reader and attaches it to the document
+    ///
+    /// Each entry is matched against the tokens of its page to find the heading it points to.
+    pub fn load_outline_reader<R: BufRead>(&mut self, reader: R) -> Result<(), Error> {
+        let mut reader = Reader::from_reader(reader);
+        let mut buffer = Vec::new();
+
+        let mut arena: Arena<OutlineEntry> = Arena::new();
+        let mut roots: Vec<NodeId> = Vec::new();
+        // Parent of each open list, `None` for top level lists
+        let mut lists: Vec<Option<NodeId>> = Vec::new();
+        let mut items: Vec<NodeId> = Vec::new();
+        // Last item of each open list, to which a following nested list belongs
+        let mut last_items: Vec<Option<NodeId>> = Vec::new();
+        let mut in_title = false;
+
+        loop {
+            let event = reader.read_event(&mut buffer)?;
+
+            match event {
+                Event::Start(ref element) | Event::Empty(ref element) => {
+                    let empty = matches!(event, Event::Empty(_));
+
+                    match element.name() {
+                        b"TOCITEMLIST" if !empty => {
+                            let parent = match (items.last(), last_items.last()) {
+                                (Some(item), _) if lists.len() <= items.len() => Some(*item),
+                                (_, Some(last_item)) => *last_item,
+                                _ => None,
+                            };
+
+                            lists.push(parent);
+                            last_items.push(None);
+                        }
+                        b"ITEM" => {
+                            let node = arena.new_node(OutlineEntry {
+                                title: String::new(),
+                                page: None,
+                                top: None,
+                                token_ids: Vec::new(),
+                            });
+
+                            match lists.last().copied().flatten() {
+                                Some(parent) => parent.append(node, &mut arena),
+                                None => roots.push(node),
+                            }
+
+                            if let Some(last_item) = last_items.last_mut() {
+                                *last_item = Some(node);
+                            }
+
+                            if !empty {
+                                items.push(node);
+                            }
+                        }
+                        b"STRING" => in_title = !empty,
+                        b"LINK" => {
+                            let attributes = Attributes::read("LINK", element)?;
+
+                            if let Some(entry) = items.last().and_then(|item| arena.get_mut(*item))
+                            {
+                                let entry = entry.get_mut();
+                                entry.page = attributes.integer("page")?;
+                                entry.top = attributes.float("top")?;
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+                Event::Text(ref content) if in_title => {
+                    if let Some(entry) = items.last().and_then(|item| arena.get_mut(*item)) {
+                        entry
+                            .get_mut()
+                            .title
+                            .push_str(&String::from_utf8_lossy(&content.unescaped()?));
+                    }
+                }
+                Event::End(ref element) => match element.name() {
+                    b"TOCITEMLIST" => {
+                        lists.pop();
+                        last_items.pop();
+                    }
+                    b"ITEM" => {
+                        items.pop();
+                    }
+                    b"STRING" => in_title = false,
+                    _ => (),
+                },
+                Event::Eof => break,
+                _ => (),
+            }
+
+            buffer.clear();
+        }
+
+        let nodes = roots
+            .iter()
+            .flat_map(|root| root.descendants(&arena))
+            .collect::<Vec<NodeId>>();
+
+        for node in nodes {
+            let entry = match arena.get_mut(node) {
+                Some(node) => node.get_mut(),
+                None => continue,
+            };
+
+            entry.title = entry.title.trim().to_string();
+
+            let page = entry
+                .page
+                .and_then(|number| self.pages.iter().find(|page| page.number == number));
+
+            if let Some(page) = page {
+                entry.token_ids = find_heading(page, &entry.title, entry.top);
+            }
+        }
+
+        self.outline = Some(Outline { arena, roots });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,255 @@
+//! Loading of XML files produced by the `-xml` arg of poppler's [pdftohtml](https://poppler.freedesktop.org/).
+//!
+//! Each `text` element becomes a text element held by its own block. pdftohtml doesn't describe words: tokens are derived by splitting texts on whitespaces, and their widths are estimated from the number of characters of each token. Fonts referenced by the `font` attribute are resolved against the `fontspec` elements, and `image` elements become images.
+
+use super::attributes::Attributes;
+use super::{parent, Block, DeserizalizationTokens, Document, Image, Page, Text, Token};
+use crate::error::Error;
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// A struct representing a pdftohtml `fontspec` element
+struct FontSpec {
+    size: f32,
+    family: Option<String>,
+    color: Option<String>,
+}
+
+/// A piece of the content of a `text` element sharing the same inline style
+struct Span {
+    content: String,
+    bold: bool,
+    italic: bool,
+}
+
+/// A `text` element being read
+struct PendingText {
+    attributes: Attributes,
+    spans: Vec<Span>,
+}
+
+impl Document {
+    /// Reads and parses a pdftohtml XML file
+    pub fn from_pdftohtml_path<P: AsRef<Path>>(path: P) -> Result<Document, Error> {
+        Document::from_pdftohtml_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Parses a pdftohtml XML document
+    pub fn from_pdftohtml_str(xml: &str) -> Result<Document, Error> {
+        Document::from_pdftohtml_reader(xml.as_bytes())
+    }
+
+    /// Reads and parses a pdftohtml XML document from a buffered reader
+    pub fn from_pdftohtml_reader<R: BufRead>(reader: R) -> Result<Document, Error> {
+        let mut reader = Reader::from_reader(reader);
+        let mut buffer = Vec::new();
+
+        // Fonts are defined once for the whole document
+        let mut fonts: HashMap<String, FontSpec> = HashMap::new();
+        let mut pages: Vec<Page> = Vec::new();
+
+        let mut text: Option<PendingText> = None;
+        let mut bold = 0;
+        let mut italic = 0;
+
+        loop {
+            match reader.read_event(&mut buffer)? {
+                // Empty `text`, `b` and `i` elements hold no content and are never closed
+                Event::Empty(element) if matches!(element.name(), b"text" | b"b" | b"i") => (),
+                Event::Start(element) | Event::Empty(element) => match element.name() {
+                    b"page" => {
+                        let attributes = Attributes::read("page", &element)?;
+                        let number = attributes.integer("number")?.unwrap_or(pages.len() + 1);
+
+                        pages.push(Page {
+                            id: format!("p{}", number),
+                            number,
+                            width: attributes.float("width")?,
+                            height: attributes.float("height")?,
+                            blocks: vec![],
+                            images: vec![],
+                            paths: vec![],
+                            links: vec![],
+                        });
+                    }
+                    b"fontspec" => {
+                        let attributes = Attributes::read("fontspec", &element)?;
+
+                        fonts.insert(
+                            attributes.required("id")?.to_string(),
+                            FontSpec {
+                                size: attributes.required_float("size")?,
+                                family: attributes.get("family").map(String::from),
+                                color: attributes.get("color").map(String::from),
+                            },
+                        );
+                    }
+                    b"text" => {
+                        text = Some(PendingText {
+                            attributes: Attributes::read("text", &element)?,
+                            spans: vec![],
+                        });
+                    }
+                    b"image" => {
+                        let attributes = Attributes::read("image", &element)?;
+                        let page = parent(pages.last_mut(), "image", "page")?;
+
+                        page.images.push(Image::new(
+                            format!("{}_i{}", page.id, page.images.len() + 1),
+                            attributes.required_float("left")?,
+                            attributes.required_float("top")?,
+                            attributes.required_float("width")?,
+                            attributes.required_float("height")?,
+                            attributes.get("src").map(String::from),
+                        ));
+                    }
+                    b"b" => bold += 1,
+                    b"i" => italic += 1,
+                    _ => (),
+                },
+                Event::Text(content) => {
+                    if let Some(text) = text.as_mut() {
+                        text.spans.push(Span {
+                            content: String::from_utf8_lossy(&content.unescaped()?).into_owned(),
+                            bold: bold > 0,
+                            italic: italic > 0,
+                        });
+                    }
+                }
+                Event::End(element) => match element.name() {
+                    b"text" => {
+                        if let Some(text) = text.take() {
+                            let page = parent(pages.last_mut(), "text", "page")?;
+                            let text = text.into_text(page, &fonts)?;
+
+                            page.blocks.push(Block::from_text(text));
+                        }
+                    }
+                    b"b" => bold -= 1,
+                    b"i" => italic -= 1,
+                    _ => (),
+                },
+                Event::Eof => break,
+                _ => (),
+            }
+
+            buffer.clear();
+        }
+
+        Ok(Document {
+            metadata: None,
+            pages,
+            outline: None,
+        })
+    }
+}
+
+impl PendingText {
+    /// Builds a text element, and its tokens, from a `text` element
+    fn into_text(self, page: &Page, fonts: &HashMap<String, FontSpec>) -> Result<Text, Error> {
+        let attributes = self.attributes;
+
+        let x = attributes.required_float("left")?;
+        let y = attributes.required_float("top")?;
+        let width = attributes.required_float("width")?;
+        let height = attributes.required_float("height")?;
+
+        let font_id = attributes.required("font")?;
+        let font = fonts
+            .get(font_id)
+            .ok_or_else(|| Error::UnresolvedReference {
+                element: "text",
+                attribute: "font",
+                reference: font_id.to_string(),
+                id: None,
+            })?;
+
+        let family = font.family.clone().unwrap_or_default().to_lowercase();
+
+        let id = format!("{}_t{}", page.id, page.blocks.len() + 1);
+
+        // Characters with their inline style
+        let characters = self
+            .spans
+            .iter()
+            .flat_map(|span| {
+                span.content
+                    .chars()
+                    .map(move |c| (c, span.bold, span.italic))
+            })
+            .collect::<Vec<(char, bool, bool)>>();
+
+        let character_width = if characters.is_empty() {
+            0.0
+        } else {
+            width / characters.len() as f32
+        };
+
+        let mut tokens = Vec::new();
+        let mut start: Option<usize> = None;
+
+        // A trailing whitespace closes the last token
+        for (index, (character, _, _)) in characters
+            .iter()
+            .copied()
+            .chain(std::iter::once((' ', false, false)))
+            .enumerate()
+        {
+            match (start, character.is_whitespace()) {
+                (None, false) => start = Some(index),
+                (Some(token_start), true) => {
+                    let (_, token_bold, token_italic) = characters[token_start];
+
+                    tokens.push(Token {
+                        sid: None,
+                        id: format!("{}_w{}", id, tokens.len() + 1),
+                        font_name: font.family.clone(),
+                        fixed_width: None,
+                        serif: None,
+                        symbolic: None,
+                        links: vec![],
+                        bold: token_bold || family.contains("bold"),
+                        italic: token_italic
+                            || family.contains("italic")
+                            || family.contains("oblique"),
+                        font_color: font
+                            .color
+                            .clone()
+                            .unwrap_or_else(|| String::from("#000000")),
+                        font_size: font.size,
+                        rotation: 0.0,
+                        angle: 0.0,
+                        x: x + token_start as f32 * character_width,
+                        y,
+                        base: y + height,
+                        width: (index - token_start) as f32 * character_width,
+                        height,
+                        value: Some(
+                            characters[token_start..index]
+                                .iter()
+                                .map(|(character, _, _)| character)
+                                .collect(),
+                        ),
+                    });
+
+                    start = None;
+                }
+                _ => (),
+            }
+        }
+
+        Ok(Text {
+            id,
+            x,
+            y,
+            width,
+            height,
+            tokens: DeserizalizationTokens(tokens),
+        })
+    }
+}
@@ -0,0 +1,205 @@
+//! Serialization of documents to the pdf2xml schema.
+//!
+//! Element and attribute names, `yes`/`no` booleans and ids are written the way pdf2xml writes them, so that the output can be read back by this crate or by any other tool reading pdf2xml files. Every page is written as a `PAGE` element, even pages read from files holding their blocks straight under the root element, so that their id and number are kept whatever the ids of their objects. Their `width` and `height` attributes are only written when known.
+//!
+//! The model isn't serialized with serde : the serializer of quick-xml can't write the mixed sequences of sibling elements of a page (blocks then images), nor a `TOKEN` holding both attributes and text, and the model holds derived data (links, layout fragments) which isn't part of the pdf2xml schema. Hence documents are written event by event with a quick-xml [`Writer`].
+
+use super::{Block, Document, Image, Page, Text, Token};
+use crate::error::Error;
+use crate::traits::{Coordinates, Shape};
+
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::Writer;
+use std::io::Write;
+
+/// Converts a boolean to a pdf2xml attribute value
+fn yes_no(value: bool) -> &'static str {
+    if value {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+impl Document {
+    /// Writes a document as a pdf2xml file
+    ///
+    /// ```no_run
+    /// # use pdf_shape::*;
+    /// # fn example() -> Result<(), Error> {
+    /// let document = Document::from_path("./examples/xml_sample/sample_1.xml")?;
+    /// document.write_xml(std::fs::File::create("./sample_1.copy.xml")?)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_xml<W: Write>(&self, writer: W) -> Result<(), Error> {
+        let mut writer = Writer::new_with_indent(writer, b' ', 7);
+
+        writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), None)))?;
+        writer.write_event(Event::Start(BytesStart::borrowed_name(b"DOCUMENT")))?;
+
+        if let Some(metadata) = &self.metadata {
+            metadata.write_xml(&mut writer)?;
+        }
+
+        for page in &self.pages {
+            write_page(&mut writer, page)?;
+        }
+
+        writer.write_event(Event::End(BytesEnd::borrowed(b"DOCUMENT")))?;
+
+        Ok(())
+    }
+
+    /// Returns the content of the pdf2xml file of a document
+    pub fn to_xml(&self) -> Result<String, Error> {
+        let mut xml = Vec::new();
+        self.write_xml(&mut xml)?;
+
+        Ok(String::from_utf8_lossy(&xml).into_owned())
+    }
+}
+
+fn write_page<W: Write>(writer: &mut Writer<W>, page: &Page) -> Result<(), Error> {
+    let number = page.number.to_string();
+
+    let mut element = BytesStart::borrowed_name(b"PAGE");
+    element.push_attribute(("id", page.id.as_str()));
+    element.push_attribute(("number", number.as_str()));
+
+    if let Some(width) = page.width {
+        element.push_attribute(("width", width.to_string().as_str()));
+    }
+
+    if let Some(height) = page.height {
+        element.push_attribute(("height", height.to_string().as_str()));
+    }
+
+    writer.write_event(Event::Start(element))?;
+
+    for block in &page.blocks {
+        write_block(writer, block)?;
+    }
+
+    for image in &page.images {
+        write_image(writer, image)?;
+    }
+
+    writer.write_event(Event::End(BytesEnd::borrowed(b"PAGE")))?;
+
+    Ok(())
+}
+
+fn write_block<W: Write>(writer: &mut Writer<W>, block: &Block) -> Result<(), Error> {
+    let mut element = BytesStart::borrowed_name(b"BLOCK");
+    element.push_attribute(("id", block.id.as_str()));
+    element.push_attribute(("x", block.x.to_string().as_str()));
+    element.push_attribute(("y", block.y.to_string().as_str()));
+
+    if let Some(height) = block.height {
+        element.push_attribute(("height", height.to_string().as_str()));
+    }
+
+    if let Some(width) = block.width {
+        element.push_attribute(("width", width.to_string().as_str()));
+    }
+
+    writer.write_event(Event::Start(element))?;
+
+    for text in &block.texts {
+        write_text(writer, text)?;
+    }
+
+    writer.write_event(Event::End(BytesEnd::borrowed(b"BLOCK")))?;
+
+    Ok(())
+}
+
+fn write_text<W: Write>(writer: &mut Writer<W>, text: &Text) -> Result<(), Error> {
+    let mut element = BytesStart::borrowed_name(b"TEXT");
+    element.push_attribute(("width", text.width.to_string().as_str()));
+    element.push_attribute(("height", text.height.to_string().as_str()));
+    element.push_attribute(("id", text.id.as_str()));
+    element.push_attribute(("x", text.x.to_string().as_str()));
+    element.push_attribute(("y", text.y.to_string().as_str()));
+
+    writer.write_event(Event::Start(element))?;
+
+    for token in &text.tokens.0 {
+        write_token(writer, token)?;
+    }
+
+    writer.write_event(Event::End(BytesEnd::borrowed(b"TEXT")))?;
+
+    Ok(())
+}
+
+fn write_token<W: Write>(writer: &mut Writer<W>, token: &Token) -> Result<(), Error> {
+    let mut element = BytesStart::borrowed_name(b"TOKEN");
+
+    if let Some(sid) = &token.sid {
+        element.push_attribute(("sid", sid.as_str()));
+    }
+
+    element.push_attribute(("id", token.id.as_str()));
+
+    if let Some(font_name) = &token.font_name {
+        element.push_attribute(("font-name", font_name.as_str()));
+    }
+
+    // Unknown flags are not written
+    for (name, flag) in &[
+        ("fixed-width", token.fixed_width),
+        ("serif", token.serif),
+        ("symbolic", token.symbolic),
+    ] {
+        if let Some(flag) = flag {
+            element.push_attribute((*name, yes_no(*flag)));
+        }
+    }
+
+    element.push_attribute(("bold", yes_no(token.bold)));
+    element.push_attribute(("italic", yes_no(token.italic)));
+    element.push_attribute(("font-size", token.font_size.to_string().as_str()));
+    element.push_attribute(("font-color", token.font_color.as_str()));
+    element.push_attribute(("rotation", token.rotation.to_string().as_str()));
+    element.push_attribute(("angle", token.angle.to_string().as_str()));
+    element.push_attribute(("x", token.x.to_string().as_str()));
+    element.push_attribute(("y", token.y.to_string().as_str()));
+    element.push_attribute(("base", token.base.to_string().as_str()));
+    element.push_attribute(("width", token.width.to_string().as_str()));
+    element.push_attribute(("height", token.height.to_string().as_str()));
+
+    match &token.value {
+        Some(value) => {
+            writer.write_event(Event::Start(element))?;
+            writer.write_event(Event::Text(BytesText::from_plain_str(value)))?;
+            writer.write_event(Event::End(BytesEnd::borrowed(b"TOKEN")))?;
+        }
+        None => writer.write_event(Event::Empty(element))?,
+    }
+
+    Ok(())
+}
+
+fn write_image<W: Write>(writer: &mut Writer<W>, image: &Image) -> Result<(), Error> {
+    let mut element = BytesStart::borrowed_name(b"IMAGE");
+
+    if let Some(sid) = image.sid() {
+        element.push_attribute(("sid", sid));
+    }
+
+    element.push_attribute(("id", image.id()));
+    element.push_attribute(("x", image.x().to_string().as_str()));
+    element.push_attribute(("y", image.y().to_string().as_str()));
+    element.push_attribute(("width", image.width().to_string().as_str()));
+    element.push_attribute(("height", image.height().to_string().as_str()));
+
+    if let Some(href) = image.href() {
+        element.push_attribute(("href", href));
+    }
+
+    writer.write_event(Event::Empty(element))?;
+
+    Ok(())
+}
@@ -0,0 +1,197 @@
+//! Page by page reading of pdf2xml files.
+//!
+//! Large documents don't have to be loaded at once: a [`PageReader`] reads a `PAGE` (or a `BLOCK`, a `TEXT` or an `IMAGE`) element at a time from any buffered reader and yields the pages as soon as they are complete.
+
+use super::{deserialize, Block, Image, Metadata, Page, Text};
+use crate::error::Error;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, Writer};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// An iterator over the pages of a pdf2xml file
+///
+/// ```no_run
+/// # use pdf_shape::*;
+/// # fn example() -> Result<(), Error> {
+/// for page in PageReader::from_path("./examples/xml_sample/sample_1.xml")? {
+///     let page = page?;
+///     println!("Page {} : {:?}", page.number(), page.get_fsm_tokens().mode_horizontal_spacing());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct PageReader<R: BufRead> {
+    reader: Reader<R>,
+    buffer: Vec<u8>,
+    depth: usize,
+    metadata: Option<Metadata>,
+    /// Page holding the blocks found straight under the root element, until a block of another page is found
+    pending_page: Option<Page>,
+    ready_pages: VecDeque<Page>,
+    done: bool,
+}
+
+impl PageReader<BufReader<File>> {
+    /// Opens a pdf2xml file to read it page by page
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Ok(PageReader::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+impl<R: BufRead> PageReader<R> {
+    /// Creates a page reader from a buffered reader
+    pub fn new(reader: R) -> Self {
+        PageReader {
+            reader: Reader::from_reader(reader),
+            buffer: Vec::new(),
+            depth: 0,
+            metadata: None,
+            pending_page: None,
+            ready_pages: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// Returns the metadata of the document
+    ///
+    /// ⚠️ This method returns `None` until the `METADATA` element has been read
+    pub fn metadata(&self) -> Option<&Metadata> {
+        self.metadata.as_ref()
+    }
+
+    /// Reads the events of the document until a page is complete
+    fn read_page(&mut self) -> Result<Option<Page>, Error> {
+        loop {
+            if let Some(page) = self.ready_pages.pop_front() {
+                return Ok(Some(page));
+            }
+
+            self.buffer.clear();
+
+            match self.reader.read_event(&mut self.buffer)? {
+                Event::Start(element) if self.depth == 1 => {
+                    let element = element.into_owned();
+                    let fragment = self.capture(element.clone(), false)?;
+                    self.handle_element(&element, &fragment)?;
+                }
+                Event::Empty(element) if self.depth == 1 => {
+                    let element = element.into_owned();
+                    let fragment = self.capture(element.clone(), true)?;
+                    self.handle_element(&element, &fragment)?;
+                }
+                Event::Start(_) => self.depth += 1,
+                Event::End(_) => self.depth = self.depth.saturating_sub(1),
+                Event::Eof => {
+                    self.done = true;
+                    return Ok(self.pending_page.take());
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Deserializes a child of the root element
+    fn handle_element(&mut self, element: &BytesStart, fragment: &str) -> Result<(), Error> {
+        match element.name() {
+            b"METADATA" => self.metadata = Some(deserialize(fragment)?),
+            b"PAGE" => {
+                let page: Page = deserialize(fragment)?;
+
+                if let Some(pending_page) = self.pending_page.take() {
+                    self.ready_pages.push_back(pending_page);
+                }
+
+                self.ready_pages.push_back(page);
+            }
+            b"BLOCK" => self.push_block(deserialize(fragment)?),
+            b"TEXT" => self.push_block(Block::from_text(deserialize::<Text>(fragment)?)),
+            b"IMAGE" => {
+                let image: Image = deserialize(fragment)?;
+                let number = Page::number_from_id(image.id());
+                self.pending_page(number).images.push(image);
+            }
+            _ => (),
+        }
+
+        Ok(())
+    }
+
+    /// Adds a block found straight under the root element to its page
+    fn push_block(&mut self, block: Block) {
+        let number = Page::number_from_id(&block.id);
+        self.pending_page(number).blocks.push(block);
+    }
+
+    /// Returns the page holding the elements found straight under the root element, the previous one being ready when the page number changes
+    fn pending_page(&mut self, number: Option<usize>) -> &mut Page {
+        let number = number
+            .or_else(|| self.pending_page.as_ref().map(|page| page.number))
+            .unwrap_or(1);
+
+        if self.pending_page.as_ref().map(|page| page.number) != Some(number) {
+            if let Some(pending_page) = self.pending_page.take() {
+                self.ready_pages.push_back(pending_page);
+            }
+        }
+
+        self.pending_page
+            .get_or_insert_with(|| Page::without_geometry(number, Vec::new()))
+    }
+
+    /// Copies an element and all its children into a standalone XML fragment
+    fn capture(&mut self, element: BytesStart<'static>, empty: bool) -> Result<String, Error> {
+        let mut writer = Writer::new(Vec::new());
+
+        if empty {
+            writer.write_event(Event::Empty(element))?;
+        } else {
+            writer.write_event(Event::Start(element))?;
+
+            let mut depth = 1;
+            let mut buffer = Vec::new();
+
+            while depth > 0 {
+                buffer.clear();
+
+                let event = self.reader.read_event(&mut buffer)?;
+
+                match event {
+                    Event::Start(_) => depth += 1,
+                    Event::End(_) => depth -= 1,
+                    Event::Eof => {
+                        return Err(Error::Xml(quick_xml::Error::UnexpectedEof(
+                            "element".to_string(),
+                        )))
+                    }
+                    _ => (),
+                }
+
+                writer.write_event(event)?;
+            }
+        }
+
+        Ok(String::from_utf8_lossy(&writer.into_inner()).into_owned())
+    }
+}
+
+impl<R: BufRead> Iterator for PageReader<R> {
+    type Item = Result<Page, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return self.ready_pages.pop_front().map(Ok);
+        }
+
+        match self.read_page() {
+            Ok(page) => page.map(Ok),
+            Err(error) => {
+                self.done = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
@@ -0,0 +1,586 @@
+//! Loading of the vector graphics file (`.vec`) written by pdf2xml next to the XML file.
+//!
+//! The `.vec` file is made of `GROUP` elements holding the path drawing commands (`M` to move, `L` to draw a line and `C` to draw a cubic Bézier curve) and a SVG-like `style` attribute. Each group becomes a [`Path`], and paths are attached to the page they belong to. Rectangles and straight line segments (table rulings, underlines, frames…) are derived from the paths.
+
+use super::attributes::Attributes;
+use super::{Color, Document, Page};
+use crate::error::Error;
+use crate::traits::{Alignement, Coordinates, Shape};
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path as FilePath;
+
+/// Largest difference between two coordinates for them to be considered the same, in points
+const TOLERANCE: f32 = 0.5;
+
+/// A drawing command of a path
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathSegment {
+    MoveTo {
+        x: f32,
+        y: f32,
+    },
+    LineTo {
+        x: f32,
+        y: f32,
+    },
+    CurveTo {
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+        x: f32,
+        y: f32,
+    },
+}
+
+/// A struct representing a path of the vector graphics of a page
+#[derive(Debug, Clone)]
+pub struct Path {
+    id: Option<String>,
+    page: usize,
+    stroke: Option<Color>,
+    stroke_width: Option<f32>,
+    fill: Option<Color>,
+    closed: bool,
+    segments: Vec<PathSegment>,
+}
+
+/// A struct representing a rectangle drawn by a path
+#[derive(Debug, Clone)]
+pub struct Rect {
+    page: usize,
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    stroke: Option<Color>,
+    fill: Option<Color>,
+}
+
+/// A struct representing a straight line drawn by a path
+#[derive(Debug, Clone)]
+pub struct LineSegment {
+    page: usize,
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+    stroke: Option<Color>,
+    stroke_width: Option<f32>,
+}
+
+impl Path {
+    /// Returns the id of the path
+    ///
+    /// ⚠️ This method returns `None` if the `GROUP` element has no `id` or `sid` attribute
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Returns the number of the page of the path
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Returns the stroke colour of the path
+    ///
+    /// ⚠️ This method returns `None` if the path is not stroked
+    pub fn stroke(&self) -> Option<Color> {
+        self.stroke
+    }
+
+    /// Returns the stroke width of the path
+    pub fn stroke_width(&self) -> Option<f32> {
+        self.stroke_width
+    }
+
+    /// Returns the fill colour of the path
+    ///
+    /// ⚠️ This method returns `None` if the path is not filled
+    pub fn fill(&self) -> Option<Color> {
+        self.fill
+    }
+
+    /// Checks if the path is closed
+    pub fn closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Returns the drawing commands of the path
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    /// Returns the points of the path, including the control points of curves
+    fn points(&self) -> Vec<(f32, f32)> {
+        self.segments
+            .iter()
+            .flat_map(|segment| match *segment {
+                PathSegment::MoveTo { x, y } | PathSegment::LineTo { x, y } => vec![(x, y)],
+                PathSegment::CurveTo {
+                    x1,
+                    y1,
+                    x2,
+                    y2,
+                    x,
+                    y,
+                } => vec![(x1, y1), (x2, y2), (x, y)],
+            })
+            .collect::<Vec<(f32, f32)>>()
+    }
+
+    /// Returns the straight lines drawn by the path
+    ///
+    /// Curves are ignored, and closed paths are joined back to their starting point
+    pub fn line_segments(&self) -> Vec<LineSegment> {
+        let mut line_segments = Vec::new();
+        let mut start: Option<(f32, f32)> = None;
+        let mut current: Option<(f32, f32)> = None;
+
+        for segment in &self.segments {
+            match *segment {
+                PathSegment::MoveTo { x, y } => {
+                    start = Some((x, y));
+                    current = Some((x, y));
+                }
+                PathSegment::LineTo { x, y } => {
+                    if let Some(from) = current {
+                        line_segments.push(self.line_segment(from, (x, y)));
+                    }
+
+                    current = Some((x, y));
+                }
+                PathSegment::CurveTo { x, y, .. } => current = Some((x, y)),
+            }
+        }
+
+        if let (true, Some(from), Some(to)) = (self.closed, current, start) {
+            if (from.0 - to.0).abs() > TOLERANCE || (from.1 - to.1).abs() > TOLERANCE {
+                line_segments.push(self.line_segment(from, to));
+            }
+        }
+
+        line_segments
+    }
+
+    fn line_segment(&self, from: (f32, f32), to: (f32, f32)) -> LineSegment {
+        LineSegment {
+            page: self.page,
+            x1: from.0,
+            y1: from.1,
+            x2: to.0,
+            y2: to.1,
+            stroke: self.stroke,
+            stroke_width: self.stroke_width,
+        }
+    }
+
+    /// Returns the rectangle drawn by the path
+    ///
+    /// ⚠️ This method returns `None` if the path is not made of four straight lines forming an axis-aligned rectangle
+    pub fn as_rect(&self) -> Option<Rect> {
+        let mut corners: Vec<(f32, f32)> = Vec::new();
+
+        for (index, segment) in self.segments.iter().enumerate() {
+            match (index, *segment) {
+                (0, PathSegment::MoveTo { x, y }) | (1..=4, PathSegment::LineTo { x, y }) => {
+                    let known = corners.iter().any(|corner| {
+                        (corner.0 - x).abs() <= TOLERANCE && (corner.1 - y).abs() <= TOLERANCE
+                    });
+
+                    if !known {
+                        corners.push((x, y));
+                    }
+                }
+                _ => return None,
+            }
+        }
+
+        if corners.len() != 4 {
+            return None;
+        }
+
+        let distinct = |values: Vec<f32>| {
+            let mut distinct: Vec<f32> = Vec::new();
+
+            for value in values {
+                if !distinct.iter().any(|v| (v - value).abs() <= TOLERANCE) {
+                    distinct.push(value);
+                }
+            }
+
+            distinct
+        };
+
+        let xs = distinct(corners.iter().map(|corner| corner.0).collect());
+        let ys = distinct(corners.iter().map(|corner| corner.1).collect());
+
+        if xs.len() != 2 || ys.len() != 2 {
+            return None;
+        }
+
+        Some(Rect {
+            page: self.page,
+            x: xs[0].min(xs[1]),
+            y: ys[0].min(ys[1]),
+            width: (xs[0] - xs[1]).abs(),
+            height: (ys[0] - ys[1]).abs(),
+            stroke: self.stroke,
+            fill: self.fill,
+        })
+    }
+}
+
+impl Coordinates for Path {
+    fn x(&self) -> f32 {
+        self.points()
+            .iter()
+            .map(|point| point.0)
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    fn y(&self) -> f32 {
+        self.points()
+            .iter()
+            .map(|point| point.1)
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    fn base(&self) -> f32 {
+        self.points()
+            .iter()
+            .map(|point| point.1)
+            .fold(f32::NEG_INFINITY, f32::max)
+    }
+}
+
+impl Shape for Path {
+    fn width(&self) -> f32 {
+        let right = self
+            .points()
+            .iter()
+            .map(|point| point.0)
+            .fold(f32::NEG_INFINITY, f32::max);
+
+        right - self.x()
+    }
+
+    fn height(&self) -> f32 {
+        self.base() - self.y()
+    }
+
+    fn rotation(&self) -> Option<f32> {
+        None
+    }
+
+    fn angle(&self) -> Option<f32> {
+        None
+    }
+}
+
+impl Alignement for Path {}
+
+impl Rect {
+    /// Returns the number of the page of the rectangle
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Returns the stroke colour of the rectangle
+    ///
+    /// ⚠️ This method returns `None` if the rectangle is not stroked
+    pub fn stroke(&self) -> Option<Color> {
+        self.stroke
+    }
+
+    /// Returns the fill colour of the rectangle
+    ///
+    /// ⚠️ This method returns `None` if the rectangle is not filled
+    pub fn fill(&self) -> Option<Color> {
+        self.fill
+    }
+}
+
+impl Coordinates for Rect {
+    fn x(&self) -> f32 {
+        self.x
+    }
+
+    fn y(&self) -> f32 {
+        self.y
+    }
+
+    fn base(&self) -> f32 {
+        self.y + self.height
+    }
+}
+
+impl Shape for Rect {
+    fn width(&self) -> f32 {
+        self.width
+    }
+
+    fn height(&self) -> f32 {
+        self.height
+    }
+
+    fn rotation(&self) -> Option<f32> {
+        None
+    }
+
+    fn angle(&self) -> Option<f32> {
+        None
+    }
+}
+
+impl Alignement for Rect {}
+
+impl LineSegment {
+    /// Returns the number of the page of the line
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Returns the starting point of the line
+    pub fn start(&self) -> (f32, f32) {
+        (self.x1, self.y1)
+    }
+
+    /// Returns the ending point of the line
+    pub fn end(&self) -> (f32, f32) {
+        (self.x2, self.y2)
+    }
+
+    /// Returns the stroke colour of the line
+    ///
+    /// ⚠️ This method returns `None` if the line is not stroked
+    pub fn stroke(&self) -> Option<Color> {
+        self.stroke
+    }
+
+    /// Returns the stroke width of the line
+    pub fn stroke_width(&self) -> Option<f32> {
+        self.stroke_width
+    }
+
+    /// Returns the length of the line
+    pub fn length(&self) -> f32 {
+        (self.x2 - self.x1).hypot(self.y2 - self.y1)
+    }
+
+    /// Checks if the line is horizontal (e.g. a table ruling or an underline)
+    pub fn is_horizontal(&self) -> bool {
+        (self.y2 - self.y1).abs() <= TOLERANCE
+    }
+
+    /// Checks if the line is vertical
+    pub fn is_vertical(&self) -> bool {
+        (self.x2 - self.x1).abs() <= TOLERANCE
+    }
+}
+
+impl Coordinates for LineSegment {
+    fn x(&self) -> f32 {
+        self.x1.min(self.x2)
+    }
+
+    fn y(&self) -> f32 {
+        self.y1.min(self.y2)
+    }
+
+    fn base(&self) -> f32 {
+        self.y1.max(self.y2)
+    }
+}
+
+impl Shape for LineSegment {
+    fn width(&self) -> f32 {
+        (self.x2 - self.x1).abs()
+    }
+
+    fn height(&self) -> f32 {
+        (self.y2 - self.y1).abs()
+    }
+
+    fn rotation(&self) -> Option<f32> {
+        None
+    }
+
+    fn angle(&self) -> Option<f32> {
+        None
+    }
+}
+
+impl Alignement for LineSegment {}
+
+/// Parses a SVG-like style attribute (e.g. `stroke: #000000;stroke-width: 0.5;fill: none;`)
+fn apply_style(path: &mut Path, style: &str) {
+    for declaration in style.split(';') {
+        let mut parts = declaration.splitn(2, ':');
+
+        let (property, value) = match (parts.next(), parts.next()) {
+            (Some(property), Some(value)) => (property.trim(), value.trim()),
+            _ => continue,
+        };
+
+        match property {
+            "stroke" => path.stroke = Color::from_hex(value),
+            "stroke-width" => path.stroke_width = value.parse::<f32>().ok(),
+            "fill" => path.fill = Color::from_hex(value),
+            _ => (),
+        }
+    }
+}
+
+/// Reads a drawing command element
+fn segment(element: &BytesStart) -> Result<Option<PathSegment>, Error> {
+    let segment = match element.name() {
+        b"M" => {
+            let attributes = Attributes::read("M", element)?;
+
+            PathSegment::MoveTo {
+                x: attributes.required_float("x")?,
+                y: attributes.required_float("y")?,
+            }
+        }
+        b"L" => {
+            let attributes = Attributes::read("L", element)?;
+
+            PathSegment::LineTo {
+                x: attributes.required_float("x")?,
+                y: attributes.required_float("y")?,
+            }
+        }
+        b"C" => {
+            let attributes = Attributes::read("C", element)?;
+
+            PathSegment::CurveTo {
+                x1: attributes.required_float("x1")?,
+                y1: attributes.required_float("y1")?,
+                x2: attributes.required_float("x2")?,
+                y2: attributes.required_float("y2")?,
+                x: attributes.required_float("x3")?,
+                y: attributes.required_float("y3")?,
+            }
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(segment))
+}
+
+impl Document {
+    /// Reads a `.vec` file written by pdf2xml and attaches its paths to the pages of the document
+    pub fn load_vec_path<P: AsRef<FilePath>>(&mut self, path: P) -> Result<(), Error> {
+        self.load_vec_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Parses the content of a `.vec` file and attaches its paths to the pages of the document
+    pub fn load_vec_str(&mut self, xml: &str) -> Result<(), Error> {
+        self.load_vec_reader(xml.as_bytes())
+    }
+
+    /// Reads a `.vec` file from a buffered reader and attaches its paths to the pages of the document
+    ///
+    /// The page of a path is given by its enclosing `PAGE` element or by its id (e.g. `p1_s12`). Pages which are not part of the document are created.
+    pub fn load_vec_reader<R: BufRead>(&mut self, reader: R) -> Result<(), Error> {
+        let mut reader = Reader::from_reader(reader);
+        let mut buffer = Vec::new();
+
+        let mut page_number: Option<usize> = None;
+        // Groups may be nested, each group being a path of its own
+        let mut groups: Vec<Path> = Vec::new();
+
+        loop {
+            let event = reader.read_event(&mut buffer)?;
+
+            match event {
+                Event::Start(ref element) | Event::Empty(ref element) => match element.name() {
+                    b"PAGE" => {
+                        let attributes = Attributes::read("PAGE", element)?;
+
+                        page_number = attributes
+                            .integer("number")?
+                            .or_else(|| attributes.id().as_deref().and_then(Page::number_from_id));
+                    }
+                    b"GROUP" => {
+                        let attributes = Attributes::read("GROUP", element)?;
+                        let id = attributes
+                            .id()
+                            .or_else(|| attributes.get("sid").map(String::from));
+                        let parent = groups.last();
+
+                        let mut path = Path {
+                            page: id
+                                .as_deref()
+                                .and_then(Page::number_from_id)
+                                .or(page_number)
+                                .or_else(|| parent.map(|parent| parent.page))
+                                .or_else(|| self.pages.last().map(|page| page.number))
+                                .unwrap_or(1),
+                            id,
+                            stroke: parent.and_then(|parent| parent.stroke),
+                            stroke_width: parent.and_then(|parent| parent.stroke_width),
+                            fill: parent.and_then(|parent| parent.fill),
+                            closed: attributes.get("closed") == Some("true"),
+                            segments: Vec::new(),
+                        };
+
+                        if let Some(style) = attributes.get("style") {
+                            apply_style(&mut path, style);
+                        }
+
+                        if let Event::Empty(_) = event {
+                            self.push_path(path);
+                        } else {
+                            groups.push(path);
+                        }
+                    }
+                    _ => {
+                        if let (Some(segment), Some(path)) = (segment(element)?, groups.last_mut())
+                        {
+                            path.segments.push(segment);
+                        }
+                    }
+                },
+                Event::End(ref element) => match element.name() {
+                    b"GROUP" => {
+                        if let Some(path) = groups.pop() {
+                            self.push_path(path);
+                        }
+                    }
+                    b"PAGE" => page_number = None,
+                    _ => (),
+                },
+                Event::Eof => break,
+                _ => (),
+            }
+
+            buffer.clear();
+        }
+
+        Ok(())
+    }
+
+    /// Attaches a path to its page
+    fn push_path(&mut self, path: Path) {
+        if path.segments.is_empty() {
+            return;
+        }
+
+        match self.pages.iter_mut().find(|page| page.number == path.page) {
+            Some(page) => page.paths.push(path),
+            None => {
+                let mut page = Page::without_geometry(path.page, Vec::new());
+                page.paths.push(path);
+
+                self.pages.push(page);
+                self.pages.sort_by_key(|page| page.number);
+            }
+        }
+    }
+}
@@ -0,0 +1,358 @@
+//! This module contains a spatial index over the objects of a page
+//!
+//! Objects are registered in every cell of a uniform grid they cover. Region queries, nearest neighbours and directional queries only visit the cells around the queried area instead of walking every object of the page.
+//!
+//! ⚠️ Positions are relative to their page: an index should only hold the objects of a single page.
+
+use crate::geometry::BoundingBox;
+use crate::traits::{Coordinates, Shape};
+
+use std::ops::Range;
+
+/// Cells are this number of times as large as the average object of the index
+const CELL_SIZE_FACTOR: f32 = 2.0;
+
+/// Greatest number of cells along each axis of the grid
+const MAX_CELLS: f32 = 1024.0;
+
+/// The directions in which the nearest object can be looked for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+    Above,
+    Below,
+}
+
+/// A grid index answering spatial queries over a set of objects
+#[derive(Debug, Clone)]
+pub struct SpatialIndex<'a, T> {
+    objects: Vec<(&'a T, BoundingBox)>,
+    /// Top left corner of the grid
+    origin: (f32, f32),
+    cell_size: f32,
+    columns: usize,
+    rows: usize,
+    /// Positions of the objects covering each cell, row by row
+    cells: Vec<Vec<usize>>,
+}
+
+/// Checks if an object of the index is the queried object
+fn is_object<T, O>(candidate: &T, object: &O) -> bool {
+    std::ptr::eq(
+        candidate as *const T as *const (),
+        object as *const O as *const (),
+    )
+}
+
+impl<'a, T: Coordinates + Shape> SpatialIndex<'a, T> {
+    /// Builds an index over a set of objects
+    pub fn new<I: IntoIterator<Item = &'a T>>(objects: I) -> SpatialIndex<'a, T> {
+        let objects = objects
+            .into_iter()
+            .map(|object| (object, object.bounding_box()))
+            .collect::<Vec<(&'a T, BoundingBox)>>();
+
+        let bounds = match BoundingBox::enclosing(objects.iter().map(|(_, bbox)| *bbox)) {
+            Some(bounds) => bounds,
+            None => {
+                return SpatialIndex {
+                    objects,
+                    origin: (0.0, 0.0),
+                    cell_size: 1.0,
+                    columns: 0,
+                    rows: 0,
+                    cells: Vec::new(),
+                }
+            }
+        };
+
+        let average_size = objects
+            .iter()
+            .map(|(_, bbox)| bbox.width().max(bbox.height()))
+            .sum::<f32>()
+            / objects.len() as f32;
+
+        let cell_size = (average_size * CELL_SIZE_FACTOR)
+            .max(bounds.width() / MAX_CELLS)
+            .max(bounds.height() / MAX_CELLS)
+            .max(1.0);
+
+        let columns = (bounds.width() / cell_size).floor() as usize + 1;
+        let rows = (bounds.height() / cell_size).floor() as usize + 1;
+
+        let mut index = SpatialIndex {
+            objects: Vec::new(),
+            origin: (bounds.x(), bounds.y()),
+            cell_size,
+            columns,
+            rows,
+            cells: vec![Vec::new(); columns * rows],
+        };
+
+        for (position, (_, bbox)) in objects.iter().enumerate() {
+            let (columns, rows) = index.cell_range(bbox);
+
+            for row in rows {
+                for column in columns.clone() {
+                    index.cells[row * index.columns + column].push(position);
+                }
+            }
+        }
+
+        index.objects = objects;
+        index
+    }
+
+    /// Returns the number of objects of the index
+    pub fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    /// Checks if the index holds no object
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+
+    /// Returns all the objects of the index
+    pub fn objects(&self) -> Vec<&'a T> {
+        self.objects.iter().map(|(object, _)| *object).collect()
+    }
+
+    /// Returns the objects overlapping or touching a region, in the order they were indexed
+    pub fn query(&self, region: &BoundingBox) -> Vec<&'a T> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+
+        let (columns, rows) = self.cell_range(region);
+
+        let mut positions = rows
+            .flat_map(|row| columns.clone().map(move |column| (row, column)))
+            .flat_map(|(row, column)| &self.cells[row * self.columns + column])
+            .copied()
+            .filter(|position| {
+                let bbox = &self.objects[*position].1;
+
+                bbox.left() <= region.right()
+                    && region.left() <= bbox.right()
+                    && bbox.top() <= region.bottom()
+                    && region.top() <= bbox.bottom()
+            })
+            .collect::<Vec<usize>>();
+
+        positions.sort_unstable();
+        positions.dedup();
+
+        positions
+            .into_iter()
+            .map(|position| self.objects[position].0)
+            .collect()
+    }
+
+    /// Returns the `k` objects closest to an object, the closest first
+    ///
+    /// Distances are measured between the edges of the bounding boxes. The object itself is skipped if it belongs to the index.
+    pub fn nearest<O: Coordinates + Shape>(&self, object: &O, k: usize) -> Vec<&'a T> {
+        if self.is_empty() || k == 0 {
+            return Vec::new();
+        }
+
+        let bbox = object.bounding_box();
+        let (columns, rows) = self.cell_range(&bbox);
+
+        let mut positions: Vec<usize> = Vec::new();
+        let mut neighbours: Vec<(f32, usize)> = Vec::new();
+
+        for ring in 0..=self.columns.max(self.rows) {
+            positions.extend(
+                self.ring(&columns, &rows, ring)
+                    .into_iter()
+                    .flat_map(|cell| &self.cells[cell])
+                    .filter(|position| !is_object(self.objects[**position].0, object)),
+            );
+            positions.sort_unstable();
+            positions.dedup();
+
+            neighbours = positions
+                .iter()
+                .map(|position| (self.objects[*position].1.distance(&bbox), *position))
+                .collect::<Vec<(f32, usize)>>();
+            neighbours.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+            // Objects of the next rings are at least `ring` cells away
+            if neighbours.len() >= k && neighbours[k - 1].0 <= ring as f32 * self.cell_size {
+                break;
+            }
+        }
+
+        neighbours
+            .into_iter()
+            .take(k)
+            .map(|(_, position)| self.objects[position].0)
+            .collect()
+    }
+
+    /// Returns the closest object in a direction, among the objects facing the object
+    ///
+    /// ```text
+    ///            +-------+
+    ///            | above |
+    ///            +-------+
+    ///  +------+ +---------+ +-------+
+    ///  | left | | object  | | right |
+    ///  +------+ +---------+ +-------+
+    ///              +-------+
+    ///              | below |
+    ///              +-------+
+    /// ```
+    ///
+    /// ⚠️ This method returns `None` if no object of the index faces the object in this direction
+    pub fn nearest_in_direction<O: Coordinates + Shape>(
+        &self,
+        object: &O,
+        direction: Direction,
+    ) -> Option<&'a T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let bbox = object.bounding_box();
+        let (columns, rows) = self.cell_range(&bbox);
+        let cell_size = self.cell_size;
+
+        // Bands of cells parallel to the facing side of the object, closest first, with their distance to it
+        let bands: Vec<(f32, Vec<usize>)> = match direction {
+            Direction::Left => (0..columns.end)
+                .rev()
+                .map(|column| {
+                    let right = self.origin.0 + (column + 1) as f32 * cell_size;
+                    let cells = rows.clone().map(|row| row * self.columns + column);
+
+                    (bbox.left() - right, cells.collect())
+                })
+                .collect(),
+            Direction::Right => (columns.start..self.columns)
+                .map(|column| {
+                    let left = self.origin.0 + column as f32 * cell_size;
+                    let cells = rows.clone().map(|row| row * self.columns + column);
+
+                    (left - bbox.right(), cells.collect())
+                })
+                .collect(),
+            Direction::Above => (0..rows.end)
+                .rev()
+                .map(|row| {
+                    let bottom = self.origin.1 + (row + 1) as f32 * cell_size;
+                    let cells = columns.clone().map(|column| row * self.columns + column);
+
+                    (bbox.top() - bottom, cells.collect())
+                })
+                .collect(),
+            Direction::Below => (rows.start..self.rows)
+                .map(|row| {
+                    let top = self.origin.1 + row as f32 * cell_size;
+                    let cells = columns.clone().map(|column| row * self.columns + column);
+
+                    (top - bbox.bottom(), cells.collect())
+                })
+                .collect(),
+        };
+
+        let mut nearest: Option<(f32, usize)> = None;
+
+        for (distance, cells) in bands {
+            if nearest.is_some_and(|(gap, _)| gap < distance) {
+                break;
+            }
+
+            for position in cells.iter().flat_map(|cell| &self.cells[*cell]) {
+                let (candidate, candidate_bbox) = &self.objects[*position];
+
+                if is_object(*candidate, object) {
+                    continue;
+                }
+
+                let facing_horizontally =
+                    candidate_bbox.top() < bbox.bottom() && bbox.top() < candidate_bbox.bottom();
+                let facing_vertically =
+                    candidate_bbox.left() < bbox.right() && bbox.left() < candidate_bbox.right();
+
+                let gap = match direction {
+                    Direction::Left if facing_horizontally => bbox.left() - candidate_bbox.right(),
+                    Direction::Right if facing_horizontally => candidate_bbox.left() - bbox.right(),
+                    Direction::Above if facing_vertically => bbox.top() - candidate_bbox.bottom(),
+                    Direction::Below if facing_vertically => candidate_bbox.top() - bbox.bottom(),
+                    _ => continue,
+                };
+
+                // Objects overlapping the object are not next to it
+                if gap < 0.0 {
+                    continue;
+                }
+
+                if nearest.map_or(true, |(nearest_gap, nearest_position)| {
+                    gap < nearest_gap || (gap == nearest_gap && *position < nearest_position)
+                }) {
+                    nearest = Some((gap, *position));
+                }
+            }
+        }
+
+        nearest.map(|(_, position)| self.objects[position].0)
+    }
+
+    /// Returns the column of the grid holding an x position, positions outside the grid being moved to its edges
+    fn column(&self, x: f32) -> usize {
+        let column = ((x - self.origin.0) / self.cell_size).floor().max(0.0) as usize;
+
+        column.min(self.columns - 1)
+    }
+
+    /// Returns the row of the grid holding a y position, positions outside the grid being moved to its edges
+    fn row(&self, y: f32) -> usize {
+        let row = ((y - self.origin.1) / self.cell_size).floor().max(0.0) as usize;
+
+        row.min(self.rows - 1)
+    }
+
+    /// Returns the columns and the rows of the cells covered by a box
+    fn cell_range(&self, bbox: &BoundingBox) -> (Range<usize>, Range<usize>) {
+        (
+            self.column(bbox.left())..self.column(bbox.right()) + 1,
+            self.row(bbox.top())..self.row(bbox.bottom()) + 1,
+        )
+    }
+
+    /// Returns the cells which are exactly `ring` cells away from a range of cells
+    fn ring(&self, columns: &Range<usize>, rows: &Range<usize>, ring: usize) -> Vec<usize> {
+        let ring = ring as isize;
+        let left = columns.start as isize - ring;
+        let right = columns.end as isize - 1 + ring;
+        let top = rows.start as isize - ring;
+        let bottom = rows.end as isize - 1 + ring;
+
+        let valid_columns = left.max(0)..=right.min(self.columns as isize - 1);
+        let mut cells = Vec::new();
+
+        for row in top.max(0)..=bottom.min(self.rows as isize - 1) {
+            let row_start = row as usize * self.columns;
+
+            if ring == 0 || row == top || row == bottom {
+                cells.extend(
+                    valid_columns
+                        .clone()
+                        .map(|column| row_start + column as usize),
+                );
+            } else {
+                for column in [left, right].iter() {
+                    if valid_columns.contains(column) {
+                        cells.push(row_start + *column as usize);
+                    }
+                }
+            }
+        }
+
+        cells
+    }
+}
@@ -9,7 +9,7 @@
 //!
 //! ## Line detection
 //!
-//! A line is a set of objects sharing the same base or a set of objects which are horizontally aligned. Horizontal spacing between objects shouldn't be greater than the horizontal spacing mode of the document.
+//! A line is a set of objects sharing the same base or a set of objects which are horizontally aligned. Horizontal spacing between objects shouldn't be greater than the horizontal spacing mode of the document, give or take a fraction of the font size.
 //!
 //! ![Diagram lines detection](../../../../images/lines.svg)
 //!
@@ -19,10 +19,17 @@
 //!
 //! ## Columns detection
 //!
+//! Columns are separated by vertical whitespace gutters. A gutter is a vertical band crossed by (almost) no object and which is wider than the horizontal spacing mode of the document. Objects spanning over several columns (titles, headers…) are ignored while looking for gutters.
+//!
 //! ## Orphans detection
 //!
+//! An orphan is the first line of a paragraph left alone at the bottom of a page or a column. A widow is the last line of a paragraph left alone at the top of a page or a column.
+//!
 //! ![Diagram orphans detection](../../../../images/orphans.svg)
 
+use crate::geometry::BoundingBox;
+use crate::raw_document::Color;
+
 /// Get the absolute coordinates of an object or a set of objects
 pub trait Coordinates {
     /// This method returns the y position of an object or a set of objects
@@ -33,6 +40,45 @@ pub trait Coordinates {
     fn base(&self) -> f32;
 }
 
+impl<'a, OBJECTSET, OBJECT> Coordinates for OBJECTSET
+where
+    OBJECTSET: IntoIterator<Item = &'a OBJECT> + Clone,
+    OBJECT: 'a + Coordinates,
+{
+    /// Takes the lowest x among a set of objects
+    fn x(&self) -> f32 {
+        self.clone()
+            .into_iter()
+            .map(|object| object.x())
+            .fold(None, |min: Option<f32>, x| {
+                Some(min.map_or(x, |min| min.min(x)))
+            })
+            .unwrap_or(0.0)
+    }
+
+    /// Takes the lowest y among a set of objects
+    fn y(&self) -> f32 {
+        self.clone()
+            .into_iter()
+            .map(|object| object.y())
+            .fold(None, |min: Option<f32>, y| {
+                Some(min.map_or(y, |min| min.min(y)))
+            })
+            .unwrap_or(0.0)
+    }
+
+    /// Takes the greatest base among a set of objects, the base being the bottom of the set
+    fn base(&self) -> f32 {
+        self.clone()
+            .into_iter()
+            .map(|object| object.base())
+            .fold(None, |max: Option<f32>, base| {
+                Some(max.map_or(base, |max| max.max(base)))
+            })
+            .unwrap_or(0.0)
+    }
+}
+
 /// Get the shape of an object or a set of objects
 pub trait Shape {
     /// This method returns the width of an object or a set of objects
@@ -43,6 +89,13 @@ pub trait Shape {
     fn rotation(&self) -> Option<f32>;
     /// This method returns the angle of an object. None is always returned for a set of objects
     fn angle(&self) -> Option<f32>;
+    /// This method returns the bounding box of an object or a set of objects
+    fn bounding_box(&self) -> BoundingBox
+    where
+        Self: Coordinates,
+    {
+        BoundingBox::new(self.x(), self.y(), self.width(), self.height())
+    }
 }
 
 /// Get the style of an object
@@ -63,6 +116,13 @@ pub trait Style {
     ///
     /// ⚠️ This methods returns `None` if the color of an object is unknown or if all objects in a set doesn't have the same color
     fn font_color(&self) -> Option<String> {
+        self.color().map(|color| color.to_string())
+    }
+
+    /// This methods returns the font color of an object as a [`Color`]
+    ///
+    /// ⚠️ This methods returns `None` if the color of an object is unknown or if all objects in a set doesn't have the same color
+    fn color(&self) -> Option<Color> {
         None
     }
 
@@ -77,6 +137,27 @@ pub trait Style {
     fn italic(&self) -> Option<bool> {
         None
     }
+
+    /// This method checks if a object or a set of objects use a fixed-width font or not
+    ///
+    /// ⚠️ This method returns `None` if the font width of an object is unknown or if all the objects of a set doesn't have the same font width
+    fn monospace(&self) -> Option<bool> {
+        None
+    }
+
+    /// This method checks if a object or a set of objects use a serif font or not
+    ///
+    /// ⚠️ This method returns `None` if the font type of an object is unknown or if all the objects of a set doesn't have the same font type
+    fn serif(&self) -> Option<bool> {
+        None
+    }
+
+    /// This method returns the font family of an object or a set of objects (e.g. `cambria`)
+    ///
+    /// ⚠️ This method returns `None` if the font family of an object is unknown or if all the objects of a set doesn't have the same font family
+    fn font_family(&self) -> Option<String> {
+        None
+    }
 }
 
 impl<'a, OBJECTSET, OBJECT> Style for OBJECTSET
@@ -106,15 +187,36 @@ where
     fn avg_font_size(&self) -> Option<f32> {
         let objects = self.clone().into_iter();
 
-        let mut o = objects.map(|object| object.font_size());
+        let o = objects
+            .map(|object| object.font_size())
+            .collect::<Vec<Option<f32>>>();
 
-        if o.all(|object| object.is_some()) {
-            Some(stats::mean(o.flat_map(|object| object)) as f32)
+        if !o.is_empty() && o.iter().all(|object| object.is_some()) {
+            Some(stats::mean(o.into_iter().flatten()) as f32)
         } else {
             None
         }
     }
 
+    fn color(&self) -> Option<Color> {
+        let objects = self.clone().into_iter();
+
+        let mut last_color: Option<Color> = None;
+
+        for object in objects {
+            match last_color {
+                Some(_) => {
+                    if object.color() != last_color {
+                        return None;
+                    }
+                }
+                None => last_color = object.color(),
+            }
+        }
+
+        last_color
+    }
+
     fn bold(&self) -> Option<bool> {
         let objects = self.clone().into_iter();
 
@@ -152,73 +254,78 @@ where
 
         last_italic
     }
-}
 
-impl<'a, OBJECTSET, OBJECT> Shape for OBJECTSET
-where
-    OBJECTSET: IntoIterator<Item = &'a OBJECT> + Clone,
-    OBJECT: 'a + Coordinates + Shape,
-{
-    fn width(&self) -> f32 {
-        // Takes the token with the lowest x = lower bound
-        // Takes the token for which the sum of x.position + self.width is higher = upper bound
-        use std::cmp::Ordering::Equal;
-        let tokens = self.clone().into_iter();
+    fn monospace(&self) -> Option<bool> {
+        let objects = self.clone().into_iter();
 
-        let mut widths = tokens
-            .map(|token| (token.x(), token.width()))
-            .collect::<Vec<(f32, f32)>>();
+        let mut last_monospace: Option<bool> = None;
 
-        widths.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Equal));
+        for object in objects {
+            match (last_monospace, object.monospace()) {
+                // The flag of an object is unknown
+                (_, None) => return None,
+                (Some(last), Some(monospace)) if last != monospace => return None,
+                (_, monospace) => last_monospace = monospace,
+            }
+        }
 
-        let lower_bound = match widths.first() {
-            Some(token) => token.0,
-            None => 0.0,
-        };
+        last_monospace
+    }
 
-        let mut widths = widths
-            .iter()
-            .map(|(x_position, width)| x_position + width)
-            .collect::<Vec<f32>>();
+    fn serif(&self) -> Option<bool> {
+        let objects = self.clone().into_iter();
 
-        widths.sort_by(|a, b| a.partial_cmp(&b).unwrap_or(Equal));
+        let mut last_serif: Option<bool> = None;
 
-        let upper_bound = match widths.last() {
-            Some(width) => *width,
-            None => 0.0,
-        };
+        for object in objects {
+            match (last_serif, object.serif()) {
+                // The flag of an object is unknown
+                (_, None) => return None,
+                (Some(last), Some(serif)) if last != serif => return None,
+                (_, serif) => last_serif = serif,
+            }
+        }
 
-        upper_bound - lower_bound
+        last_serif
     }
 
-    fn height(&self) -> f32 {
-        use std::cmp::Ordering::Equal;
-        let tokens = self.clone().into_iter();
+    fn font_family(&self) -> Option<String> {
+        let objects = self.clone().into_iter();
 
-        let mut heights = tokens
-            .map(|token| (token.y(), token.height()))
-            .collect::<Vec<(f32, f32)>>();
+        let mut last_font_family: Option<String> = None;
 
-        heights.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Equal));
+        for object in objects {
+            match last_font_family {
+                Some(_) => {
+                    if object.font_family() != last_font_family {
+                        return None;
+                    }
+                }
+                None => last_font_family = object.font_family(),
+            }
+        }
 
-        let upper_bound = match heights.last() {
-            Some(token) => token.0,
-            None => 0.0,
-        };
+        last_font_family
+    }
+}
 
-        let mut heights = heights
-            .iter()
-            .map(|(y_position, height)| y_position - height)
-            .collect::<Vec<f32>>();
+impl<'a, OBJECTSET, OBJECT> Shape for OBJECTSET
+where
+    OBJECTSET: IntoIterator<Item = &'a OBJECT> + Clone,
+    OBJECT: 'a + Coordinates + Shape,
+{
+    fn width(&self) -> f32 {
+        let objects = self.clone().into_iter();
 
-        heights.sort_by(|a, b| a.partial_cmp(&b).unwrap_or(Equal));
+        BoundingBox::enclosing(objects.map(|object| object.bounding_box()))
+            .map_or(0.0, |bounding_box| bounding_box.width())
+    }
 
-        let lower_bound = match heights.first() {
-            Some(height) => *height,
-            None => 0.0,
-        };
+    fn height(&self) -> f32 {
+        let objects = self.clone().into_iter();
 
-        upper_bound - lower_bound
+        BoundingBox::enclosing(objects.map(|object| object.bounding_box()))
+            .map_or(0.0, |bounding_box| bounding_box.height())
     }
 
     fn rotation(&self) -> Option<f32> {
@@ -230,19 +337,28 @@ where
     }
 }
 
-#[derive(PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 /// Represents all possible alignements between a set of objects
 pub enum ObjectAlignement {
-    Alinged, // Aligned in x y
-    /// Objects are aligned horizontaly
+    /// Objects share the same top left corner
+    /// ```text
+    /// ↘+--------+
+    ///  |        |
+    ///  +--------+
+    /// ↘+------------+
+    ///  |            |
+    ///  +------------+
     /// ```
+    Alinged, // Aligned in x y
+    /// Objects are aligned horizontaly by their top side
+    /// ```text
     ///  +--------+  +---------+
     ///  |........|  |.........|
     /// →+--------+ →+---------+
     /// ```
     HorizontalAligned, // Aligned in y
     /// Objects are aligned horizontaly and are verticaly centered with each others
-    /// ```
+    /// ```text
     ///              +-------------+
     ///  +--------+  |             |
     /// →|    +   | →|      +      |
@@ -251,7 +367,7 @@ pub enum ObjectAlignement {
     /// ```
     HorizontalCenterAligned,
     /// Objects are verticaly aligned by their left side
-    /// ```
+    /// ```text
     ///  ↓
     ///  +---------+
     ///  |         |
@@ -263,7 +379,7 @@ pub enum ObjectAlignement {
     /// ```
     VerticalLeftAligned,
     /// Objects are aligned Verticaly and are horizotnaly centered with each others
-    /// ```
+    /// ```text
     ///         ↓
     ///  +------------+
     ///  |      +     |
@@ -275,7 +391,7 @@ pub enum ObjectAlignement {
     /// ```
     VerticalCenterAlgined,
     /// Objects are verticaly aligned by their right side
-    /// ```
+    /// ```text
     ///           ↓
     ///  +---------+
     ///  |         |
@@ -286,36 +402,226 @@ pub enum ObjectAlignement {
     ///     +------+
     /// ```
     VerticalRightAlgined,
+    /// Objects are aligned horizontaly by their bottom side
+    /// ```text
+    ///              +---------+
+    ///  +--------+  |         |
+    ///  |........|  |.........|
+    /// →+--------+ →+---------+
+    /// ```
+    HorizontalBottomAligned,
+    /// Objects are aligned horizontaly by their base (e.g. the baseline of tokens)
+    /// ```text
+    ///  +--------+  +---------+
+    ///  |        |  |         |
+    /// →|--------| →|---------|
+    ///  +--------+  |         |
+    ///              +---------+
+    /// ```
+    HorizontalBaselineAligned,
     /// Objects are not aligned
     NonAligned,
 }
 
+/// The greatest distance, in points, between the positions of objects which are still considered as aligned
+///
+/// pdf2xml positions are floats: two tokens of the same line rarely share exactly the same y position. A tolerance can be absolute or relative to the font size or the spacing mode of the objects.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance(f32);
+
+impl Tolerance {
+    /// Creates a tolerance of a given number of points
+    pub fn absolute(points: f32) -> Tolerance {
+        Tolerance(points.max(0.0))
+    }
+
+    /// Creates a tolerance proportional to the average font size of an object or a set of objects (e.g. `0.2` for a fifth of the font size)
+    ///
+    /// ⚠️ The tolerance is 0 if the font size is unknown
+    pub fn relative_to_font_size<S: Style>(object: &S, ratio: f32) -> Tolerance {
+        Tolerance::absolute(object.avg_font_size().unwrap_or(0.0) * ratio)
+    }
+
+    /// Creates a tolerance proportional to a spacing mode (see [`Spacing`])
+    ///
+    /// ⚠️ The tolerance is 0 if the spacing mode is unknown
+    pub fn relative_to_spacing(mode: Option<f32>, ratio: f32) -> Tolerance {
+        Tolerance::absolute(mode.unwrap_or(0.0) * ratio)
+    }
+
+    /// Returns the tolerance in points
+    pub fn points(&self) -> f32 {
+        self.0
+    }
+}
+
+/// The alignement found between objects, with how far off the objects are
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlignementMatch {
+    /// The first alignement within the tolerance, [`ObjectAlignement::NonAligned`] if none
+    ///
+    /// Bottom and baseline alignements are never returned, see [`Alignements`]
+    pub alignement: ObjectAlignement,
+    /// The greatest distance, in points, between the aligned positions of the objects
+    ///
+    /// For non aligned objects, this is the deviation of the closest alignement
+    pub deviation: f32,
+}
+
+/// Every alignement relation between an object and other objects, with how far off the objects are for each relation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alignements {
+    /// Deviation of every relation, the most specific relations first
+    deviations: Vec<(ObjectAlignement, f32)>,
+    tolerance: Tolerance,
+}
+
+impl Alignements {
+    /// Returns the relations which hold within the tolerance, the most specific relations first
+    ///
+    /// ⚠️ This method returns an empty vector if the objects are not aligned
+    pub fn relations(&self) -> Vec<ObjectAlignement> {
+        self.deviations
+            .iter()
+            .filter(|(_, deviation)| *deviation <= self.tolerance.points())
+            .map(|(alignement, _)| *alignement)
+            .collect::<Vec<ObjectAlignement>>()
+    }
+
+    /// Checks if a relation holds within the tolerance
+    ///
+    /// [`ObjectAlignement::NonAligned`] holds when no other relation does
+    pub fn contains(&self, alignement: ObjectAlignement) -> bool {
+        match alignement {
+            ObjectAlignement::NonAligned => !self.is_aligned(),
+            _ => self
+                .deviation(alignement)
+                .is_some_and(|deviation| deviation <= self.tolerance.points()),
+        }
+    }
+
+    /// Checks if at least one relation holds within the tolerance
+    pub fn is_aligned(&self) -> bool {
+        !self.relations().is_empty()
+    }
+
+    /// Returns the greatest distance, in points, between the positions compared by a relation, whether it holds or not
+    ///
+    /// ⚠️ This method returns `None` for [`ObjectAlignement::NonAligned`]
+    pub fn deviation(&self, alignement: ObjectAlignement) -> Option<f32> {
+        self.deviations
+            .iter()
+            .find(|(relation, _)| *relation == alignement)
+            .map(|(_, deviation)| *deviation)
+    }
+
+    /// Returns the tolerance used to compare positions
+    pub fn tolerance(&self) -> Tolerance {
+        self.tolerance
+    }
+}
+
+/// Returns the greatest distance between a reference position and other positions
+fn deviation<I: Iterator<Item = f32>>(reference: f32, positions: I) -> f32 {
+    positions
+        .map(|position| (position - reference).abs())
+        .fold(0.0, f32::max)
+}
+
+/// Relations returned by [`Alignement::alignement`], in the order they are checked
+///
+/// Objects sharing their top left corner are also aligned horizontally and vertically, hence [`ObjectAlignement::Alinged`] is checked first. Bottom and baseline alignements are only reported by [`Alignement::alignements`]
+const ALIGNEMENT_ORDER: [ObjectAlignement; 6] = [
+    ObjectAlignement::Alinged,
+    ObjectAlignement::HorizontalAligned,
+    ObjectAlignement::HorizontalCenterAligned,
+    ObjectAlignement::VerticalLeftAligned,
+    ObjectAlignement::VerticalCenterAlgined,
+    ObjectAlignement::VerticalRightAlgined,
+];
+
 /// Returns the alignement between two or more objects
 ///
 /// Alignement can be auto implemented for any Struct which implements Coordinates and Shape traits
 pub trait Alignement: Coordinates + Shape {
     /// This method returns the alignement of a set of objects
+    ///
+    /// Positions have to be exactly the same, see [`Alignement::alignement_with_tolerance`] for objects read from real documents
     fn alignement<X: Alignement>(&self, others: Vec<&X>) -> ObjectAlignement {
+        self.alignement_with_tolerance(others, Tolerance::absolute(0.0))
+            .alignement
+    }
+
+    /// This method returns the alignement of a set of objects and how far off they are
+    ///
+    /// Positions are considered equal if they are not further apart than the tolerance. Relations are checked in the same order as [`Alignement::alignement`]: horizontal, horizontal center, vertical left, vertical center, vertical right, then both horizontal and vertical left. See [`Alignement::alignements`] to get every relation which holds.
+    fn alignement_with_tolerance<X: Alignement>(
+        &self,
+        others: Vec<&X>,
+        tolerance: Tolerance,
+    ) -> AlignementMatch {
+        let alignements = self.alignements(others, tolerance);
+
+        let deviations = ALIGNEMENT_ORDER.iter().map(|alignement| {
+            (
+                *alignement,
+                alignements.deviation(*alignement).unwrap_or(f32::INFINITY),
+            )
+        });
+
+        match deviations
+            .clone()
+            .find(|(_, deviation)| *deviation <= tolerance.points())
+        {
+            Some((alignement, deviation)) => AlignementMatch {
+                alignement,
+                deviation,
+            },
+            None => AlignementMatch {
+                alignement: ObjectAlignement::NonAligned,
+                deviation: deviations
+                    .map(|(_, deviation)| deviation)
+                    .fold(f32::INFINITY, f32::min),
+            },
+        }
+    }
+
+    /// This method returns every alignement relation between an object and a set of objects
+    ///
+    /// Positions are considered equal if they are not further apart than the tolerance
+    fn alignements<X: Alignement>(&self, others: Vec<&X>, tolerance: Tolerance) -> Alignements {
+        let others = others.iter();
+
         //  +--------+  +---------+
         //  |........|  |.........|
         // →+--------+ →+---------+
-
-        if others.iter().all(|elem| elem.y() == self.y()) {
-            return ObjectAlignement::HorizontalAligned;
-        }
+        let horizontal = deviation(self.y(), others.clone().map(|elem| elem.y()));
 
         //              +-------------+
         //  +--------+  |             |
         // →|    +   | →|      +      |
         //  +--------+  |             |
         //              +-------------+
+        let horizontal_center = deviation(
+            self.height() / 2.0 + self.y(),
+            others.clone().map(|elem| elem.height() / 2.0 + elem.y()),
+        );
 
-        if others
-            .iter()
-            .all(|elem| elem.height() / 2.0 + elem.y() == self.height() / 2.0 + self.y())
-        {
-            return ObjectAlignement::HorizontalCenterAligned;
-        }
+        //              +---------+
+        //  +--------+  |         |
+        //  |........|  |.........|
+        // →+--------+ →+---------+
+        let horizontal_bottom = deviation(
+            self.height() + self.y(),
+            others.clone().map(|elem| elem.height() + elem.y()),
+        );
+
+        //  +--------+  +---------+
+        //  |        |  |         |
+        // →|--------| →|---------|
+        //  +--------+  |         |
+        //              +---------+
+        let horizontal_baseline = deviation(self.base(), others.clone().map(|elem| elem.base()));
 
         //  ↓
         //  +---------+
@@ -325,10 +631,7 @@ pub trait Alignement: Coordinates + Shape {
         //  +------+
         //  |      |
         //  +------+
-
-        if others.iter().all(|elem| elem.x() == self.x()) {
-            return ObjectAlignement::VerticalLeftAligned;
-        }
+        let vertical_left = deviation(self.x(), others.clone().map(|elem| elem.x()));
 
         //         ↓
         //  +------------+
@@ -338,15 +641,12 @@ pub trait Alignement: Coordinates + Shape {
         //    +--------+
         //    |    +   |
         //    +--------+
+        let vertical_center = deviation(
+            self.width() / 2.0 + self.x(),
+            others.clone().map(|elem| elem.width() / 2.0 + elem.x()),
+        );
 
-        if others
-            .iter()
-            .all(|elem| elem.width() / 2.0 + elem.x() == self.width() / 2.0 + self.x())
-        {
-            return ObjectAlignement::VerticalCenterAlgined;
-        }
-
-        //            ↓
+        //            ↓
         //  +---------+
         //  |         |
         //  +---------+
@@ -354,22 +654,27 @@ pub trait Alignement: Coordinates + Shape {
         //     +------+
         //     |      |
         //     +------+
-
-        if others
-            .iter()
-            .all(|elem| elem.width() + elem.x() == self.width() + self.x())
-        {
-            return ObjectAlignement::VerticalRightAlgined;
+        let vertical_right = deviation(
+            self.width() + self.x(),
+            others.map(|elem| elem.width() + elem.x()),
+        );
+
+        Alignements {
+            deviations: vec![
+                (ObjectAlignement::Alinged, horizontal.max(vertical_left)),
+                (ObjectAlignement::HorizontalAligned, horizontal),
+                (ObjectAlignement::HorizontalCenterAligned, horizontal_center),
+                (ObjectAlignement::VerticalLeftAligned, vertical_left),
+                (ObjectAlignement::VerticalCenterAlgined, vertical_center),
+                (ObjectAlignement::VerticalRightAlgined, vertical_right),
+                (ObjectAlignement::HorizontalBottomAligned, horizontal_bottom),
+                (
+                    ObjectAlignement::HorizontalBaselineAligned,
+                    horizontal_baseline,
+                ),
+            ],
+            tolerance,
         }
-
-        if others
-            .iter()
-            .all(|elem| elem.y() == self.y() && elem.x() == self.x())
-        {
-            return ObjectAlignement::Alinged;
-        };
-
-        ObjectAlignement::NonAligned
     }
 }
 
@@ -406,18 +711,17 @@ where
                 let spacing = current_token.base();
                 let last_spacing = *vertical_spacing.last().unwrap_or(&0.0);
 
-                let mut v = Vec::with_capacity(1);
-                v.push(*next_token);
+                // Objects are on the same line if they share exactly the same positions
+                let alignements =
+                    current_token.alignements(vec![*next_token], Tolerance::absolute(0.0));
 
                 // Only take into account tokens on different lines
-                match current_token.alignement(v) {
-                    ObjectAlignement::HorizontalAligned
-                    | ObjectAlignement::HorizontalCenterAligned => (),
-                    _ => {
-                        if spacing > 0.0 && spacing > last_spacing {
-                            vertical_spacing.push(next_token.y() - spacing);
-                        }
-                    }
+                if !alignements.contains(ObjectAlignement::HorizontalAligned)
+                    && !alignements.contains(ObjectAlignement::HorizontalCenterAligned)
+                    && spacing > 0.0
+                    && spacing > last_spacing
+                {
+                    vertical_spacing.push(next_token.y() - spacing);
                 }
             }
         }
@@ -449,12 +753,13 @@ where
                 let spacing = current_token.x() + current_token.width();
                 let last_spacing = *horizontal_spacing.last().unwrap_or(&0.0);
 
-                let mut v = Vec::with_capacity(1);
-                v.push(*next_token);
+                // Objects are on the same line if they share exactly the same positions
+                let alignements =
+                    current_token.alignements(vec![*next_token], Tolerance::absolute(0.0));
 
                 // Only take into account tokens on the same line
-                if (ObjectAlignement::HorizontalAligned == current_token.alignement(v.clone())
-                    || ObjectAlignement::HorizontalCenterAligned == current_token.alignement(v))
+                if (alignements.contains(ObjectAlignement::HorizontalAligned)
+                    || alignements.contains(ObjectAlignement::HorizontalCenterAligned))
                     && spacing > 0.0
                     && spacing > last_spacing
                 {
@@ -478,3 +783,233 @@ where
         stats::mode(self.horizontal_spacing().iter().map(|value| value.round()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Rectangle {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        base: f32,
+        font_size: Option<f32>,
+    }
+
+    impl Rectangle {
+        fn new(x: f32, y: f32, width: f32, height: f32) -> Rectangle {
+            Rectangle {
+                x,
+                y,
+                width,
+                height,
+                base: y + height,
+                font_size: None,
+            }
+        }
+
+        fn with_base(self, base: f32) -> Rectangle {
+            Rectangle { base, ..self }
+        }
+
+        fn with_font_size(self, font_size: f32) -> Rectangle {
+            Rectangle {
+                font_size: Some(font_size),
+                ..self
+            }
+        }
+    }
+
+    impl Coordinates for Rectangle {
+        fn x(&self) -> f32 {
+            self.x
+        }
+
+        fn y(&self) -> f32 {
+            self.y
+        }
+
+        fn base(&self) -> f32 {
+            self.base
+        }
+    }
+
+    impl Shape for Rectangle {
+        fn width(&self) -> f32 {
+            self.width
+        }
+
+        fn height(&self) -> f32 {
+            self.height
+        }
+
+        fn rotation(&self) -> Option<f32> {
+            None
+        }
+
+        fn angle(&self) -> Option<f32> {
+            None
+        }
+    }
+
+    impl Style for Rectangle {
+        fn font_size(&self) -> Option<f32> {
+            self.font_size
+        }
+
+        fn avg_font_size(&self) -> Option<f32> {
+            self.font_size
+        }
+    }
+
+    impl Alignement for Rectangle {}
+
+    #[test]
+    fn absolute_tolerances_are_never_negative() {
+        assert_eq!(Tolerance::absolute(2.5).points(), 2.5);
+        assert_eq!(Tolerance::absolute(0.0).points(), 0.0);
+        assert_eq!(Tolerance::absolute(-1.0).points(), 0.0);
+    }
+
+    #[test]
+    fn tolerances_are_relative_to_the_average_font_size() {
+        let small = Rectangle::new(0.0, 0.0, 10.0, 10.0).with_font_size(10.0);
+        let large = Rectangle::new(0.0, 20.0, 10.0, 20.0).with_font_size(20.0);
+        let unknown = Rectangle::new(0.0, 0.0, 10.0, 10.0);
+
+        assert_eq!(Tolerance::relative_to_font_size(&small, 0.2).points(), 2.0);
+        assert_eq!(
+            Tolerance::relative_to_font_size(&vec![&small, &large], 0.5).points(),
+            7.5
+        );
+        assert_eq!(
+            Tolerance::relative_to_font_size(&unknown, 0.2).points(),
+            0.0
+        );
+        assert_eq!(Tolerance::relative_to_font_size(&small, -0.2).points(), 0.0);
+    }
+
+    #[test]
+    fn tolerances_are_relative_to_the_spacing_mode() {
+        assert_eq!(
+            Tolerance::relative_to_spacing(Some(12.0), 0.25).points(),
+            3.0
+        );
+        assert_eq!(Tolerance::relative_to_spacing(None, 0.25).points(), 0.0);
+    }
+
+    #[test]
+    fn deviations_are_the_greatest_distance_between_positions() {
+        let first = Rectangle::new(0.0, 10.0, 40.0, 12.0);
+        let second = Rectangle::new(50.0, 10.5, 30.0, 12.0);
+        let third = Rectangle::new(90.0, 9.0, 30.0, 12.0);
+
+        let alignements = first.alignements(vec![&second, &third], Tolerance::absolute(1.0));
+
+        assert_eq!(
+            alignements.deviation(ObjectAlignement::HorizontalAligned),
+            Some(1.0)
+        );
+        assert_eq!(
+            alignements.deviation(ObjectAlignement::VerticalLeftAligned),
+            Some(90.0)
+        );
+        assert_eq!(alignements.deviation(ObjectAlignement::NonAligned), None);
+        assert_eq!(alignements.tolerance(), Tolerance::absolute(1.0));
+    }
+
+    #[test]
+    fn alignements_are_found_within_the_tolerance() {
+        let first = Rectangle::new(0.0, 10.0, 40.0, 12.0);
+        let second = Rectangle::new(50.0, 10.5, 30.0, 12.0);
+
+        assert_eq!(
+            first.alignement_with_tolerance(vec![&second], Tolerance::absolute(1.0)),
+            AlignementMatch {
+                alignement: ObjectAlignement::HorizontalAligned,
+                deviation: 0.5,
+            }
+        );
+
+        // Non aligned objects report the deviation of the closest alignement
+        assert_eq!(
+            first.alignement_with_tolerance(vec![&second], Tolerance::absolute(0.25)),
+            AlignementMatch {
+                alignement: ObjectAlignement::NonAligned,
+                deviation: 0.5,
+            }
+        );
+        assert_eq!(
+            first.alignement(vec![&second]),
+            ObjectAlignement::NonAligned
+        );
+    }
+
+    #[test]
+    fn alignement_checks_the_most_specific_relation_first() {
+        let first = Rectangle::new(10.0, 10.0, 40.0, 12.0);
+        let same = Rectangle::new(10.0, 10.0, 40.0, 12.0);
+        let beside = Rectangle::new(60.0, 10.0, 20.0, 12.0);
+        let below = Rectangle::new(10.0, 30.0, 20.0, 12.0);
+
+        // Objects sharing their top left corner are aligned
+        assert_eq!(first.alignement(vec![&same]), ObjectAlignement::Alinged);
+        assert_eq!(
+            first.alignement(vec![&beside]),
+            ObjectAlignement::HorizontalAligned
+        );
+        assert_eq!(
+            first.alignement(vec![&below]),
+            ObjectAlignement::VerticalLeftAligned
+        );
+        assert_eq!(
+            first
+                .alignements(vec![&same], Tolerance::absolute(0.0))
+                .relations()[0],
+            ObjectAlignement::Alinged
+        );
+    }
+
+    #[test]
+    fn bottom_and_baseline_alignements_are_only_reported_as_relations() {
+        let first = Rectangle::new(0.0, 10.0, 40.0, 12.0).with_base(20.0);
+        let taller = Rectangle::new(50.0, 6.0, 30.0, 16.0).with_base(18.0);
+        let lower = Rectangle::new(90.0, 12.0, 30.0, 14.0).with_base(20.0);
+
+        let bottom = first.alignements(vec![&taller], Tolerance::absolute(0.0));
+
+        assert_eq!(
+            bottom.relations(),
+            vec![ObjectAlignement::HorizontalBottomAligned]
+        );
+        assert!(!bottom.contains(ObjectAlignement::NonAligned));
+        assert_eq!(
+            first.alignement(vec![&taller]),
+            ObjectAlignement::NonAligned
+        );
+
+        let baseline = first.alignements(vec![&lower], Tolerance::absolute(0.0));
+
+        assert_eq!(
+            baseline.relations(),
+            vec![ObjectAlignement::HorizontalBaselineAligned]
+        );
+        assert!(baseline.contains(ObjectAlignement::HorizontalBaselineAligned));
+        assert!(!baseline.contains(ObjectAlignement::HorizontalBottomAligned));
+        assert_eq!(first.alignement(vec![&lower]), ObjectAlignement::NonAligned);
+    }
+
+    #[test]
+    fn non_aligned_objects_hold_no_relation() {
+        let first = Rectangle::new(0.0, 0.0, 10.0, 10.0);
+        let other = Rectangle::new(20.0, 30.0, 15.0, 5.0);
+
+        let alignements = first.alignements(vec![&other], Tolerance::absolute(1.0));
+
+        assert!(alignements.relations().is_empty());
+        assert!(!alignements.is_aligned());
+        assert!(alignements.contains(ObjectAlignement::NonAligned));
+        assert!(!alignements.contains(ObjectAlignement::Alinged));
+    }
+}
@@ -0,0 +1,150 @@
+use pdf_shape::*;
+
+const SAMPLE: &str = "./examples/alto_sample/sample_1.xml";
+
+#[test]
+fn alto_tokens_are_loaded() {
+    let document = Document::from_alto_path(SAMPLE).unwrap();
+    let tokens = document.get_fsm_tokens();
+
+    let values = tokens
+        .tokens
+        .iter()
+        .map(|token| token.value.clone().unwrap_or_default())
+        .collect::<Vec<String>>();
+
+    assert_eq!(values, vec!["Thèse", "code", "unstyled"]);
+
+    let positions = tokens
+        .tokens
+        .iter()
+        .map(|token| {
+            (
+                token.x(),
+                token.y(),
+                token.width(),
+                token.height(),
+                token.base(),
+            )
+        })
+        .collect::<Vec<(f32, f32, f32, f32, f32)>>();
+
+    assert_eq!(
+        positions,
+        vec![
+            (56.0, 70.0, 40.0, 14.0, 81.0),
+            (100.0, 70.0, 30.0, 14.0, 81.0),
+            (56.0, 240.0, 60.0, 11.0, 251.0),
+        ]
+    );
+
+    let images = document.get_pages_borrowed()[0].get_images_borrowed();
+
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].href(), Some("sample_1.xml_data/image-1.png"));
+}
+
+#[test]
+fn alto_styles_are_resolved() {
+    let document = Document::from_alto_path(SAMPLE).unwrap();
+    let tokens = document.get_fsm_tokens();
+
+    // Inherited from the TextBlock, the paragraph style being skipped
+    let inherited = tokens.tokens[0];
+    assert_eq!(inherited.font_name(), Some("cambria"));
+    assert_eq!(inherited.font_size(), Some(12.0));
+    assert_eq!(inherited.bold(), Some(true));
+    assert_eq!(inherited.italic(), Some(false));
+    assert_eq!(inherited.serif(), Some(true));
+    assert_eq!(inherited.monospace(), Some(false));
+    assert_eq!(inherited.color(), Color::from_hex("#000000"));
+
+    // Referenced by the String, its STYLE overriding the FONTSTYLE of the text style
+    let referenced = tokens.tokens[1];
+    assert_eq!(referenced.font_name(), Some("courier"));
+    assert_eq!(referenced.font_size(), Some(9.0));
+    assert_eq!(referenced.bold(), Some(false));
+    assert_eq!(referenced.italic(), Some(true));
+    assert_eq!(referenced.monospace(), Some(true));
+    assert_eq!(referenced.color(), Color::from_hex("#ff0000"));
+    assert_eq!(referenced.font_color().as_deref(), Some("#ff0000"));
+
+    // Without any STYLEREFS
+    // Font colors are only shared by sets of tokens of the same color
+    assert_eq!(tokens.color(), None);
+    assert_eq!(tokens.color_histogram()[0], (Color::new(0, 0, 0), 2));
+
+    let unstyled = tokens.tokens[2];
+    assert_eq!(unstyled.font_name(), None);
+    assert_eq!(unstyled.font_size(), Some(11.0));
+    assert_eq!(unstyled.bold(), Some(false));
+    assert_eq!(unstyled.color(), Color::from_hex("#000000"));
+}
+
+#[test]
+fn unresolved_style_references_are_reported() {
+    let xml = std::fs::read_to_string(SAMPLE)
+        .unwrap()
+        .replace(r#"STYLEREFS="font1""#, r#"STYLEREFS="font9""#);
+
+    match Document::from_alto_str(&xml) {
+        Err(Error::UnresolvedReference { reference, id, .. }) => {
+            assert_eq!(reference, "font9");
+            assert_eq!(id.as_deref(), Some("p1_w2"));
+        }
+        other => panic!("unexpected result: {:?}", other.map(|_| ())),
+    }
+}
+
+/// Compares positions converted to points, up to rounding errors
+fn assert_close(left: f32, right: f32) {
+    assert!((left - right).abs() < 1e-4, "{} != {}", left, right);
+}
+
+#[test]
+fn alto_positions_are_converted_to_points() {
+    let xml = std::fs::read_to_string(SAMPLE).unwrap();
+    let pixels = Document::from_alto_str(&xml).unwrap();
+
+    for (unit, ratio) in [("mm10", 72.0 / 254.0), ("inch1200", 72.0 / 1200.0)].iter() {
+        let document = Document::from_alto_str(&xml.replace(
+            "<MeasurementUnit>pixel",
+            &format!("<MeasurementUnit>{}", unit),
+        ))
+        .unwrap();
+
+        let page = document.get_pages_borrowed()[0];
+        assert_close(page.width().unwrap(), 595.0 * ratio);
+        assert_close(page.height().unwrap(), 842.0 * ratio);
+
+        let image = page.get_images_borrowed()[0];
+        assert_close(image.x(), 56.0 * ratio);
+        assert_close(image.width(), 200.0 * ratio);
+
+        let tokens = document.get_fsm_tokens();
+
+        for (token, pixel) in tokens.tokens.iter().zip(pixels.get_fsm_tokens().tokens) {
+            assert_close(token.x(), pixel.x() * ratio);
+            assert_close(token.y(), pixel.y() * ratio);
+            assert_close(token.width(), pixel.width() * ratio);
+            assert_close(token.height(), pixel.height() * ratio);
+            assert_close(token.base(), pixel.base() * ratio);
+        }
+
+        // Font sizes are given in points
+        assert_eq!(tokens.tokens[0].font_size(), Some(12.0));
+        assert_close(tokens.tokens[2].font_size().unwrap(), 11.0 * ratio);
+    }
+}
+
+#[test]
+fn unsupported_measurement_units_are_reported() {
+    let xml = std::fs::read_to_string(SAMPLE)
+        .unwrap()
+        .replace("<MeasurementUnit>pixel", "<MeasurementUnit>furlong");
+
+    match Document::from_alto_str(&xml) {
+        Err(Error::UnsupportedUnit(unit)) => assert_eq!(unit, "furlong"),
+        other => panic!("unexpected result: {:?}", other.map(|_| ())),
+    }
+}
@@ -0,0 +1,108 @@
+use pdf_shape::*;
+
+const SAMPLE: &str = "./examples/xml_sample/sample_1.xml";
+const ANNOTATIONS_SAMPLE: &str = "./examples/annotation_sample/sample_1_annot.xml";
+
+fn document() -> Document {
+    let mut document = Document::from_path(SAMPLE).unwrap();
+    document.load_annotations_path(ANNOTATIONS_SAMPLE).unwrap();
+    document
+}
+
+#[test]
+fn links_are_attached_to_their_pages() {
+    let document = document();
+    let pages = document.get_pages_borrowed();
+
+    // Pages missing from the document are inserted in page order, without any token
+    let numbers = pages
+        .iter()
+        .map(|page| page.number())
+        .collect::<Vec<usize>>();
+    assert_eq!(numbers, vec![1, 2, 3]);
+    assert!(pages[1].get_fsm_tokens().tokens.is_empty());
+    assert!(pages[2].get_fsm_tokens().tokens.is_empty());
+
+    let targets = pages
+        .iter()
+        .map(|page| {
+            page.get_links_borrowed()
+                .into_iter()
+                .map(|link| link.target().clone())
+                .collect::<Vec<LinkTarget>>()
+        })
+        .collect::<Vec<Vec<LinkTarget>>>();
+
+    // The comment annotation of page 2 is not a link
+    assert_eq!(
+        targets,
+        vec![
+            vec![
+                LinkTarget::Uri(String::from("https://theses.fr/jury")),
+                LinkTarget::Uri(String::from("mailto:jury@theses.fr")),
+            ],
+            vec![LinkTarget::Destination {
+                page: Some(3),
+                x: None,
+                y: None,
+            }],
+            vec![LinkTarget::Destination {
+                page: Some(1),
+                x: Some(70.0),
+                y: Some(504.0),
+            }],
+        ]
+    );
+
+    let mail = pages[0].get_links_borrowed()[1];
+    assert_eq!(mail.areas().len(), 2);
+    assert_eq!(mail.uri(), Some("mailto:jury@theses.fr"));
+    assert_eq!(
+        (mail.x(), mail.y(), mail.width(), mail.height()),
+        (70.0, 505.0, 220.0, 13.0)
+    );
+}
+
+#[test]
+fn links_are_attached_to_the_tokens_they_cover() {
+    let document = document();
+    let page = document.get_pages_borrowed()[0];
+
+    let linked = page
+        .get_fsm_tokens()
+        .tokens
+        .into_iter()
+        .filter(|token| !token.links().is_empty())
+        .map(|token| {
+            (
+                token.value.clone().unwrap_or_default(),
+                token
+                    .links()
+                    .iter()
+                    .map(|link| link.uri().unwrap_or_default().to_string())
+                    .collect::<Vec<String>>(),
+            )
+        })
+        .collect::<Vec<(String, Vec<String>)>>();
+
+    // The link of page 3 covers the same area as the mail link, but on another page
+    assert_eq!(
+        linked,
+        vec![
+            (
+                String::from("JURY"),
+                vec![String::from("https://theses.fr/jury")]
+            ),
+            (
+                String::from("Directrice"),
+                vec![String::from("mailto:jury@theses.fr")]
+            ),
+            (
+                String::from("Carole"),
+                vec![String::from("mailto:jury@theses.fr")]
+            ),
+        ]
+    );
+
+    assert_eq!(page.get_fsm_tokens().links().len(), 2);
+}
@@ -0,0 +1,102 @@
+use pdf_shape::*;
+
+const SAMPLES: [&str; 2] = [
+    "./examples/xml_sample/sample_1.xml",
+    "./examples/xml_sample/sample_2.xml",
+];
+
+/// Rounding errors allowed between the position of a set and the positions of its objects
+const EPSILON: f32 = 0.001;
+
+/// Checks that the position and the size of a set describe the box enclosing all its objects
+fn check_set<'a, S, O>(name: &str, set: &S)
+where
+    S: Coordinates + Shape + IntoIterator<Item = &'a O> + Clone,
+    O: 'a + Coordinates + Shape,
+{
+    let objects = set.clone().into_iter().collect::<Vec<&O>>();
+
+    if objects.is_empty() {
+        return;
+    }
+
+    let left = objects.iter().map(|o| o.x()).fold(f32::INFINITY, f32::min);
+    let top = objects.iter().map(|o| o.y()).fold(f32::INFINITY, f32::min);
+    let right = objects
+        .iter()
+        .map(|o| o.x() + o.width())
+        .fold(f32::NEG_INFINITY, f32::max);
+    let bottom = objects
+        .iter()
+        .map(|o| o.y() + o.height())
+        .fold(f32::NEG_INFINITY, f32::max);
+    let base = objects
+        .iter()
+        .map(|o| o.base())
+        .fold(f32::NEG_INFINITY, f32::max);
+
+    assert!((set.x() - left).abs() < EPSILON, "{}: x", name);
+    assert!((set.y() - top).abs() < EPSILON, "{}: y", name);
+    assert!(
+        (set.x() + set.width() - right).abs() < EPSILON,
+        "{}: width",
+        name
+    );
+    assert!(
+        (set.y() + set.height() - bottom).abs() < EPSILON,
+        "{}: height",
+        name
+    );
+    assert!((set.base() - base).abs() < EPSILON, "{}: base", name);
+
+    let bounding_box = set.bounding_box();
+
+    for object in objects {
+        let object_box = object.bounding_box();
+        let shrunk = BoundingBox::from_edges(
+            object_box.left() + EPSILON,
+            object_box.top() + EPSILON,
+            object_box.right() - EPSILON,
+            object_box.bottom() - EPSILON,
+        );
+
+        assert!(bounding_box.contains(&shrunk), "{}: object outside", name);
+    }
+}
+
+#[test]
+fn sets_are_described_by_their_bounding_box() {
+    for sample in SAMPLES.iter() {
+        let document = Document::from_path(sample).expect(sample);
+
+        check_set("document tokens", &document.get_fsm_tokens());
+
+        for page in document.get_pages_borrowed() {
+            check_set("page tokens", &page.get_fsm_tokens());
+
+            for block in page.get_blocks_borrowed() {
+                check_set("block tokens", &block.get_fsm_tokens());
+            }
+
+            for text in page.get_texts_borrowed() {
+                check_set("text tokens", &text.get_fsm_tokens());
+            }
+
+            let lines = page.get_lines();
+
+            for line in &lines {
+                check_set("line", line);
+            }
+
+            check_set("lines", &lines.iter());
+
+            for paragraph in &page.get_paragraphs() {
+                check_set("paragraph", paragraph);
+            }
+
+            for column in &page.get_columns() {
+                check_set("column", column);
+            }
+        }
+    }
+}
@@ -0,0 +1,195 @@
+//! Builders of small pdf2xml documents shared by the integration tests
+//!
+//! Tokens are written in a 10pt black arial font. Texts and blocks enclose their children.
+
+// Each test crate only uses some of the builders
+#![allow(dead_code)]
+
+use std::fmt;
+
+/// A `TOKEN` element
+#[derive(Debug, Clone)]
+pub struct TokenElement {
+    id: String,
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    base: f32,
+    bold: bool,
+    value: String,
+}
+
+/// An element enclosing other elements
+#[derive(Debug, Clone)]
+pub struct Element {
+    xml: String,
+    bounds: (f32, f32, f32, f32),
+}
+
+/// Returns a token whose base lies at five sixths of its height
+pub fn token(id: &str, x: f32, y: f32, width: f32, height: f32, value: &str) -> TokenElement {
+    TokenElement {
+        id: id.to_string(),
+        x,
+        y,
+        width,
+        height,
+        base: y + height * 5.0 / 6.0,
+        bold: false,
+        value: value.to_string(),
+    }
+}
+
+impl TokenElement {
+    /// Moves the base of the token
+    pub fn base(self, base: f32) -> TokenElement {
+        TokenElement { base, ..self }
+    }
+
+    /// Writes the token in bold
+    pub fn bold(self) -> TokenElement {
+        TokenElement { bold: true, ..self }
+    }
+
+    fn bounds(&self) -> (f32, f32, f32, f32) {
+        (self.x, self.y, self.x + self.width, self.y + self.height)
+    }
+}
+
+impl fmt::Display for TokenElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Token ids end with `_w<n>`, their sid with `_s<n>`
+        let sid = match self.id.rfind("_w") {
+            Some(position) => format!("{}_s{}", &self.id[..position], &self.id[position + 2..]),
+            None => format!("{}_s", self.id),
+        };
+
+        write!(
+            f,
+            r##"<TOKEN sid="{}" id="{}" font-name="arial" bold="{}" italic="no" font-size="10" font-color="#000000" rotation="0" angle="0" x="{}" y="{}" base="{}" width="{}" height="{}">{}</TOKEN>"##,
+            sid,
+            self.id,
+            if self.bold { "yes" } else { "no" },
+            self.x,
+            self.y,
+            self.base,
+            self.width,
+            self.height,
+            self.value
+        )
+    }
+}
+
+impl Element {
+    /// Returns the box enclosing a set of boxes as `(left, top, right, bottom)`
+    fn enclosing<I: Iterator<Item = (f32, f32, f32, f32)>>(boxes: I) -> (f32, f32, f32, f32) {
+        boxes.fold(
+            (
+                f32::INFINITY,
+                f32::INFINITY,
+                f32::NEG_INFINITY,
+                f32::NEG_INFINITY,
+            ),
+            |(left, top, right, bottom), (l, t, r, b)| {
+                (left.min(l), top.min(t), right.max(r), bottom.max(b))
+            },
+        )
+    }
+
+    fn new(name: &str, id: &str, bounds: (f32, f32, f32, f32), children: String) -> Element {
+        let (left, top, right, bottom) = bounds;
+
+        Element {
+            xml: format!(
+                r#"<{name} id="{id}" x="{x}" y="{y}" width="{width}" height="{height}">
+{children}
+</{name}>"#,
+                name = name,
+                id = id,
+                x = left,
+                y = top,
+                width = right - left,
+                height = bottom - top,
+                children = children
+            ),
+            bounds,
+        }
+    }
+}
+
+impl fmt::Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.xml)
+    }
+}
+
+/// Returns a `TEXT` element holding tokens
+pub fn text(id: &str, tokens: &[TokenElement]) -> Element {
+    Element::new(
+        "TEXT",
+        id,
+        Element::enclosing(tokens.iter().map(TokenElement::bounds)),
+        join(tokens),
+    )
+}
+
+/// Returns a `BLOCK` element holding texts
+pub fn block(id: &str, texts: &[Element]) -> Element {
+    Element::new(
+        "BLOCK",
+        id,
+        Element::enclosing(texts.iter().map(|text| text.bounds)),
+        join(texts),
+    )
+}
+
+/// Returns an `IMAGE` element
+pub fn image(id: &str, x: f32, y: f32, width: f32, height: f32) -> String {
+    format!(
+        r#"<IMAGE id="{id}" sid="{id}" x="{x}" y="{y}" width="{width}" height="{height}" href="sample.xml_data/{id}.png"/>"#,
+        id = id,
+        x = x,
+        y = y,
+        width = width,
+        height = height
+    )
+}
+
+/// Returns an A4 `PAGE` element
+pub fn page(number: usize, body: &str) -> String {
+    sized_page(number, 595.0, 842.0, body)
+}
+
+/// Returns a `PAGE` element of a given size
+pub fn sized_page(number: usize, width: f32, height: f32, body: &str) -> String {
+    format!(
+        r#"<PAGE id="p{number}" number="{number}" width="{width}" height="{height}">
+{body}
+</PAGE>"#,
+        number = number,
+        width = width,
+        height = height,
+        body = body
+    )
+}
+
+/// Returns a pdf2xml document
+pub fn document(body: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" ?>
+<DOCUMENT>
+{}
+</DOCUMENT>"#,
+        body
+    )
+}
+
+/// Writes elements one after the other
+pub fn join<T: fmt::Display>(elements: &[T]) -> String {
+    elements
+        .iter()
+        .map(|element| element.to_string())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use common::*;
+use pdf_shape::*;
+use std::str::FromStr;
+
+/// A document whose second token is changed by `edit`
+fn sample<F: Fn(String) -> String>(edit: F) -> String {
+    let tokens = join(&[
+        token("p1_w1", 10.0, 10.0, 40.0, 12.0, "first").to_string(),
+        edit(token("p1_w2", 60.0, 10.0, 40.0, 12.0, "second").to_string()),
+    ]);
+
+    document(&format!(
+        r#"<BLOCK id="p1_b1" x="10" y="10" width="90" height="12">
+<TEXT id="p1_t1" x="10" y="10" width="90" height="12">
+{}
+</TEXT>
+</BLOCK>"#,
+        tokens
+    ))
+}
+
+#[test]
+fn missing_attributes_are_reported_with_the_element_id() {
+    for attribute in ["font-size", "base"].iter() {
+        let xml = sample(|token| token.replace(&format!(r#" {}="#, attribute), " removed="));
+
+        match Document::from_str(&xml) {
+            Err(Error::MissingAttribute {
+                element,
+                attribute: missing,
+                id,
+            }) => {
+                assert_eq!(element, "TOKEN");
+                assert_eq!(missing, *attribute);
+                assert_eq!(id.as_deref(), Some("p1_w2"));
+            }
+            other => panic!("{}: {:?}", attribute, other),
+        }
+    }
+
+    let error = Document::from_str(&sample(|token| token.replace(" base=", " removed=")))
+        .unwrap_err()
+        .to_string();
+
+    assert_eq!(error, "missing attribute `base` on TOKEN (id `p1_w2`)");
+}
+
+#[test]
+fn invalid_numbers_are_reported_with_the_element_id() {
+    let xml = sample(|token| token.replace(r#"x="60""#, r#"x="sixty""#));
+
+    match Document::from_str(&xml) {
+        Err(Error::ParseNumber {
+            element,
+            attribute,
+            value,
+            id,
+        }) => {
+            assert_eq!(element, "TOKEN");
+            assert_eq!(attribute, "x");
+            assert_eq!(value, "sixty");
+            assert_eq!(id.as_deref(), Some("p1_w2"));
+        }
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn malformed_xml_is_reported() {
+    let xml = sample(|token| token.replace("</TOKEN>", "</TEXT>"));
+
+    assert!(matches!(Document::from_str(&xml), Err(Error::Xml(_))));
+}
+
+#[test]
+fn missing_files_are_reported() {
+    match Document::from_path("./examples/xml_sample/missing.xml") {
+        Err(Error::Io(error)) => assert_eq!(error.kind(), std::io::ErrorKind::NotFound),
+        other => panic!("{:?}", other),
+    }
+}
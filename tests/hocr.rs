@@ -0,0 +1,141 @@
+use pdf_shape::*;
+
+const SAMPLES: [&str; 2] = [
+    "./examples/xml_sample/sample_1.xml",
+    "./examples/xml_sample/sample_2.xml",
+];
+
+/// hOCR bounding boxes are written with integer coordinates
+const ROUNDING: f32 = 0.5;
+
+#[test]
+fn hocr_export_round_trips() {
+    for sample in SAMPLES.iter() {
+        let document = Document::from_path(sample).expect(sample);
+
+        let mut hocr = Vec::new();
+        document.write_hocr(&mut hocr).unwrap();
+        let imported = Document::from_hocr_reader(hocr.as_slice()).unwrap();
+
+        assert_eq!(
+            imported.get_pages_borrowed().len(),
+            document.get_pages_borrowed().len(),
+            "{}",
+            sample
+        );
+
+        let tokens = document.get_fsm_tokens().tokens;
+        let imported_tokens = imported.get_fsm_tokens().tokens;
+
+        assert_eq!(imported_tokens.len(), tokens.len(), "{}", sample);
+
+        for (token, imported_token) in tokens.iter().zip(imported_tokens.iter()) {
+            assert_eq!(imported_token.id(), token.id());
+            assert_eq!(imported_token.value, token.value, "{}", token.id());
+            assert_eq!(imported_token.bold(), token.bold(), "{}", token.id());
+            assert_eq!(imported_token.italic(), token.italic(), "{}", token.id());
+            assert_eq!(
+                imported_token.font_size(),
+                token.font_size(),
+                "{}",
+                token.id()
+            );
+            // Bases are written as the baselines of the lines
+            assert!(
+                (imported_token.base() - token.base()).abs() <= ROUNDING,
+                "{}: {} != {}",
+                token.id(),
+                imported_token.base(),
+                token.base()
+            );
+
+            let bbox = token.bounding_box();
+            let imported_bbox = imported_token.bounding_box();
+
+            for (edge, imported_edge) in [
+                (bbox.left(), imported_bbox.left()),
+                (bbox.top(), imported_bbox.top()),
+                (bbox.right(), imported_bbox.right()),
+                (bbox.bottom(), imported_bbox.bottom()),
+            ]
+            .iter()
+            {
+                assert!(
+                    (edge - imported_edge).abs() <= ROUNDING,
+                    "{}: {:?} != {:?}",
+                    token.id(),
+                    bbox,
+                    imported_bbox
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn hocr_lines_and_words_outside_areas_are_wrapped() {
+    let html = r#"<html><body>
+<div class="ocr_page" id="page_1" title="bbox 0 0 600 800; ppageno 0">
+<a href="https://example.com/?first=1&second=2">Link</a>
+<span class="ocr_line" id="line_1" title="bbox 10 10 110 22; baseline 0 -2; x_size 12">
+<span class="ocrx_word" id="word_1" title="bbox 10 10 50 22">first</span>
+<span class="ocrx_word" id="word_2" title="bbox 60 10 110 22">line</span>
+</span>
+<div class="ocr_carea" id="area_1" title="bbox 10 40 110 52">
+<span class="ocrx_word" id="word_3" title="bbox 10 40 50 52">loose</span>
+</div>
+<span class="ocrx_word" id="word_4" title="bbox 10 70 50 82">alone</span>
+</div>
+</body></html>"#;
+
+    let document = Document::from_hocr_str(html).unwrap();
+    let page = document.get_pages_borrowed()[0];
+
+    let blocks = page
+        .get_blocks_borrowed()
+        .iter()
+        .map(|block| (block.id().to_string(), block.y(), block.height()))
+        .collect::<Vec<(String, f32, f32)>>();
+
+    assert_eq!(
+        blocks,
+        vec![
+            ("page_1_b1".to_string(), 10.0, 12.0),
+            ("area_1".to_string(), 40.0, 12.0),
+            ("page_1_b3".to_string(), 70.0, 12.0),
+        ]
+    );
+
+    let texts = page
+        .get_texts_borrowed()
+        .iter()
+        .map(|text| {
+            (
+                text.id().to_string(),
+                text.get_fsm_tokens()
+                    .tokens
+                    .iter()
+                    .map(|token| token.id().to_string())
+                    .collect::<Vec<String>>(),
+            )
+        })
+        .collect::<Vec<(String, Vec<String>)>>();
+
+    assert_eq!(
+        texts,
+        vec![
+            (
+                "line_1".to_string(),
+                vec!["word_1".to_string(), "word_2".to_string()]
+            ),
+            ("page_1_t2".to_string(), vec!["word_3".to_string()]),
+            ("page_1_t3".to_string(), vec!["word_4".to_string()]),
+        ]
+    );
+
+    let tokens = page.get_fsm_tokens().tokens;
+
+    assert_eq!(tokens[0].base(), 20.0);
+    assert_eq!(tokens[0].font_size(), Some(12.0));
+    assert_eq!(tokens[2].base(), 52.0);
+}
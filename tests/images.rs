@@ -0,0 +1,101 @@
+mod common;
+
+use common::*;
+use pdf_shape::*;
+use std::str::FromStr;
+
+/// A block holding a single token
+fn word(id: &str, y: f32, value: &str) -> Element {
+    block(
+        &format!("{}_b", id),
+        &[text(
+            &format!("{}_t", id),
+            &[token(&format!("{}_w", id), 10.0, y, 40.0, 12.0, value)],
+        )],
+    )
+}
+
+fn values(page: &Page) -> Vec<String> {
+    page.get_fsm_tokens()
+        .tokens
+        .iter()
+        .map(|token| token.value.clone().unwrap_or_default())
+        .collect()
+}
+
+fn image_ids(page: &Page) -> Vec<&str> {
+    page.get_images_borrowed()
+        .into_iter()
+        .map(|image| image.id())
+        .collect()
+}
+
+#[test]
+fn images_between_blocks_of_a_page_are_read() {
+    let xml = document(&page(
+        1,
+        &join(&[
+            word("p1_1", 10.0, "before").to_string(),
+            image("p1_i1", 10.0, 30.0, 100.0, 50.0),
+            word("p1_2", 90.0, "after").to_string(),
+            image("p1_i2", 10.0, 110.0, 100.0, 50.0),
+        ]),
+    ));
+
+    let document = Document::from_str(&xml).unwrap();
+    let pages = document.get_pages_borrowed();
+
+    assert_eq!(pages.len(), 1);
+    assert_eq!(values(pages[0]), vec!["before", "after"]);
+    assert_eq!(image_ids(pages[0]), vec!["p1_i1", "p1_i2"]);
+}
+
+#[test]
+fn images_between_blocks_outside_pages_are_read() {
+    let xml = document(&join(&[
+        word("p1_1", 10.0, "first").to_string(),
+        image("p1_i1", 10.0, 30.0, 100.0, 50.0),
+        word("p2_1", 10.0, "second").to_string(),
+        image("p2_i1", 10.0, 30.0, 100.0, 50.0),
+    ]));
+
+    let document = Document::from_str(&xml).unwrap();
+    let pages = document.get_pages_borrowed();
+
+    assert_eq!(pages.len(), 2);
+    assert_eq!(values(pages[0]), vec!["first"]);
+    assert_eq!(image_ids(pages[0]), vec!["p1_i1"]);
+    assert_eq!(values(pages[1]), vec!["second"]);
+    assert_eq!(image_ids(pages[1]), vec!["p2_i1"]);
+}
+
+#[test]
+fn images_without_area_overlap_the_tokens_they_cross() {
+    let xml = document(&page(
+        1,
+        &join(&[
+            word("p1_1", 100.0, "crossed").to_string(),
+            word("p1_2", 300.0, "apart").to_string(),
+            image("p1_i1", 30.0, 0.0, 0.0, 200.0),
+            image("p1_i2", 10.0, 112.0, 40.0, 30.0),
+        ]),
+    ));
+
+    let document = Document::from_str(&xml).unwrap();
+    let page = &document.get_pages_borrowed()[0];
+    let tokens = page.get_fsm_tokens();
+    let images = page.get_images_borrowed();
+
+    let overlapping = |image: &Image| {
+        image
+            .get_overlapping_tokens(&tokens)
+            .tokens
+            .iter()
+            .map(|token| token.id().to_string())
+            .collect::<Vec<String>>()
+    };
+
+    assert_eq!(overlapping(images[0]), vec!["p1_1_w"]);
+    // Images which only share an edge with a token don't overlap it
+    assert!(overlapping(images[1]).is_empty());
+}
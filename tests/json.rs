@@ -0,0 +1,107 @@
+mod common;
+
+use common::*;
+use pdf_shape::*;
+use serde_json::Value;
+use std::str::FromStr;
+
+/// Two tokens of the same line whose top sides are half a point apart
+fn sample() -> String {
+    document(
+        &block(
+            "p1_b1",
+            &[text(
+                "p1_t1",
+                &[
+                    token("p1_w1", 10.0, 10.0, 40.0, 12.0, "first"),
+                    token("p1_w2", 60.0, 10.5, 30.0, 12.0, "second")
+                        .base(20.0)
+                        .bold(),
+                ],
+            )],
+        )
+        .to_string(),
+    )
+}
+
+fn export(options: &JsonOptions) -> Value {
+    let document = Document::from_str(&sample()).unwrap();
+    serde_json::from_str(&document.to_json(options).unwrap()).unwrap()
+}
+
+#[test]
+fn documents_are_exported() {
+    let json = export(&JsonOptions::default());
+
+    assert_eq!(json["version"], JSON_SCHEMA_VERSION);
+    assert_eq!(json["metadata"], Value::Null);
+
+    let page = &json["pages"][0];
+    assert_eq!(page["id"], "p1");
+    assert_eq!(page["number"], 1);
+    assert!(page.get("analyses").is_none());
+
+    let text = &page["blocks"][0]["texts"][0];
+    assert_eq!(text["id"], "p1_t1");
+    assert_eq!(text["style"]["font_size"], 10.0);
+    assert_eq!(text["style"]["font_color"], "#000000");
+    assert_eq!(text["style"]["bold"], Value::Null);
+
+    let token = &text["tokens"][1];
+    assert_eq!(token["id"], "p1_w2");
+    assert_eq!(token["value"], "second");
+    assert_eq!(token["style"]["bold"], true);
+    assert_eq!(
+        token["bbox"],
+        serde_json::json!({"x": 60.0, "y": 10.5, "width": 30.0, "height": 12.0, "base": 20.0})
+    );
+}
+
+#[test]
+fn alignements_are_exported_within_a_tolerance() {
+    let options = JsonOptions {
+        alignement: true,
+        ..JsonOptions::default()
+    };
+
+    let json = export(&options);
+    let analyses = &json["pages"][0]["blocks"][0]["texts"][0]["analyses"];
+
+    // The default tolerance is a fifth of the font size
+    assert_eq!(
+        analyses["alignements"],
+        serde_json::json!([
+            "horizontal",
+            "horizontal_center",
+            "horizontal_bottom",
+            "horizontal_baseline"
+        ])
+    );
+    // Exact positions are compared, bottom and baseline alignements aren't reported
+    assert_eq!(analyses["alignement"], "none");
+    assert!(analyses.get("spacing").is_none());
+
+    let exact = export(&JsonOptions {
+        alignement_tolerance_ratio: 0.0,
+        ..options
+    });
+
+    assert_eq!(
+        exact["pages"][0]["blocks"][0]["texts"][0]["analyses"]["alignements"],
+        serde_json::json!(["horizontal_baseline"])
+    );
+}
+
+#[test]
+fn spacing_is_exported() {
+    let json = export(&JsonOptions {
+        spacing: true,
+        ..JsonOptions::default()
+    });
+
+    let analyses = &json["pages"][0]["blocks"][0]["texts"][0]["analyses"];
+
+    assert!(analyses["spacing"]["horizontal"].is_array());
+    assert!(analyses["spacing"]["vertical"].is_array());
+    assert!(analyses.get("alignements").is_none());
+}
@@ -0,0 +1,198 @@
+mod common;
+
+use common::*;
+use pdf_shape::*;
+use std::str::FromStr;
+
+fn lines(sample: &str) -> Vec<String> {
+    let document = Document::from_path(sample).expect(sample);
+
+    document
+        .get_lines()
+        .iter()
+        .map(|line| line.to_string())
+        .collect::<Vec<String>>()
+}
+
+#[test]
+fn words_separated_by_wide_gaps_stay_on_their_line() {
+    let lines = lines("./examples/xml_sample/sample_1.xml");
+
+    // Justified line with gaps of about 8pt
+    assert!(lines.contains(&"Anne BIENFIT, Maître de Conférences".to_string()));
+    assert!(lines.contains(&"Directeur du Pôle Services à la Personne ISÈRE".to_string()));
+
+    // Words on both sides of a gutter are on different lines
+    assert!(lines.contains(&"Rapporteurs".to_string()));
+    assert!(lines.contains(&"Philippe BOYAD, Professeur des Universités,".to_string()));
+}
+
+#[test]
+fn words_of_different_sizes_share_their_line() {
+    let lines = lines("./examples/xml_sample/sample_2.xml");
+
+    assert!(lines.contains(&"Pr. Jean Heureux".to_string()));
+    assert!(lines.contains(&"Pr. Etienne Bravier".to_string()));
+    assert!(lines.contains(&"Dr. Virk Dan Lester".to_string()));
+    assert!(lines.contains(&"Rapporteur  Professeur à l'Université de Lorraine".to_string()));
+    assert_eq!(lines.len(), 21);
+}
+
+fn paragraphs(sample: &str) -> Vec<String> {
+    let document = Document::from_path(sample).expect(sample);
+
+    document
+        .get_paragraphs()
+        .iter()
+        .map(|paragraph| paragraph.to_string())
+        .collect::<Vec<String>>()
+}
+
+#[test]
+fn equally_spaced_lines_are_grouped_into_paragraphs() {
+    let paragraphs = paragraphs("./examples/xml_sample/sample_1.xml");
+
+    assert!(paragraphs.contains(
+        &[
+            "Philippe BOYAD, Professeur des Universités,",
+            "Conservatoire National des Arts et Métiers",
+            "Anne BIENFIT, Maître de Conférences",
+            "HDR, Université de Nantes",
+        ]
+        .join("\n")
+    ));
+    assert!(paragraphs.contains(
+        &[
+            "François BARITON,",
+            "Directeur du Pôle Services à la Personne ISÈRE",
+            "Solène FERNANDEZ, Professeure des Universités,",
+            "Université d’Aix Marseille",
+        ]
+        .join("\n")
+    ));
+
+    // Labels of the left column are separated from the names by a gutter
+    assert!(paragraphs.contains(&"Rapporteurs".to_string()));
+    assert_eq!(paragraphs.len(), 7);
+}
+
+#[test]
+fn centered_lines_are_grouped_into_paragraphs() {
+    let paragraphs = paragraphs("./examples/xml_sample/sample_2.xml");
+
+    assert!(paragraphs.contains(
+        &[
+            "Laboratoire d'accueil :",
+            "Institut de Recherche sur la Fusion par connement Magnétique",
+            "GEA  Cadarache",
+            "13108 Saint-Paul-lez-Durance, France",
+        ]
+        .join("\n")
+    ));
+    assert!(paragraphs.contains(&"Oct 2015  Nov 2019".to_string()));
+}
+
+#[test]
+fn columns_are_separated_by_gutters() {
+    let document = Document::from_path("./examples/xml_sample/sample_1.xml").unwrap();
+    let columns = document.get_columns();
+
+    assert_eq!(columns.len(), 2);
+    assert_eq!(
+        columns[0]
+            .get_lines()
+            .iter()
+            .map(|line| line.to_string())
+            .collect::<Vec<String>>(),
+        vec!["Directrice de recherche", "Rapporteurs", "Suffragants"]
+    );
+    assert_eq!(columns[1].tokens.len(), 46);
+
+    let document = Document::from_path("./examples/xml_sample/sample_2.xml").unwrap();
+
+    assert_eq!(document.get_columns().len(), 1);
+}
+
+/// A TEXT element holding a single token
+fn word(id: usize, x: f32, y: f32, value: &str) -> Element {
+    text(
+        &format!("p1_t{}", id),
+        &[token(&format!("p1_w{}", id), x, y, 40.0, 12.0, value)],
+    )
+}
+
+#[test]
+fn outlier_tokens_dont_break_column_detection() {
+    let mut texts = Vec::new();
+
+    for row in 0..5 {
+        let y = 100.0 + row as f32 * 14.0;
+
+        texts.push(word(row * 3, 50.0, y, "left"));
+        texts.push(word(row * 3 + 1, 300.0, y, "right"));
+    }
+
+    // A bogus position, as written for some broken PDFs
+    texts.push(word(100, 1.0e9, 100.0, "outlier"));
+
+    let xml = document(&block("p1_b1", &texts).to_string());
+
+    let document = Document::from_str(&xml).unwrap();
+    let columns = document.get_columns();
+
+    assert_eq!(columns.len(), 3);
+    assert!(columns[0].tokens.iter().all(|token| token.x() == 50.0));
+    assert!(columns[1].tokens.iter().all(|token| token.x() == 300.0));
+    assert_eq!(columns[2].tokens.len(), 1);
+}
+
+#[test]
+fn lines_left_alone_at_column_boundaries_are_flagged() {
+    let mut texts = Vec::new();
+    let mut line = |x: f32, y: f32, value: &str| {
+        let id = texts.len();
+        texts.push(word(id, x, y, value));
+    };
+
+    // First column: a paragraph, then the first line of a paragraph split by the boundary
+    line(50.0, 100.0, "first");
+    line(50.0, 114.0, "first");
+    line(50.0, 128.0, "first");
+    line(50.0, 200.0, "orphan");
+    // Second column: the rest of the split paragraph, then the beginning of another one
+    line(300.0, 100.0, "rest");
+    line(300.0, 114.0, "rest");
+    line(300.0, 128.0, "rest");
+    line(300.0, 200.0, "split");
+    line(300.0, 214.0, "split");
+    // Third column: the last line of the previous paragraph, then another paragraph
+    line(550.0, 100.0, "widow");
+    line(550.0, 200.0, "last");
+    line(550.0, 214.0, "last");
+    line(550.0, 228.0, "last");
+
+    let xml = document(&block("p1_b1", &texts).to_string());
+
+    let document = Document::from_str(&xml).unwrap();
+    let paragraphs = document
+        .get_columns()
+        .iter()
+        .map(|column| column.get_paragraphs())
+        .collect::<Vec<Vec<Paragraph>>>();
+
+    assert_eq!(paragraphs.len(), 3);
+
+    let orphans = Orphan::detect(&paragraphs);
+
+    assert_eq!(orphans.len(), 2);
+
+    assert_eq!(orphans[0].kind, OrphanKind::Orphan);
+    assert_eq!(orphans[0].token_ids(), vec!["p1_w3"]);
+    assert_eq!(orphans[0].fragment.to_string(), "orphan");
+    assert_eq!(orphans[0].paragraph.to_string(), "rest\nrest\nrest");
+
+    assert_eq!(orphans[1].kind, OrphanKind::Widow);
+    assert_eq!(orphans[1].token_ids(), vec!["p1_w9"]);
+    assert_eq!(orphans[1].fragment.to_string(), "widow");
+    assert_eq!(orphans[1].paragraph.to_string(), "split\nsplit");
+}
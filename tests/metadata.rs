@@ -0,0 +1,75 @@
+mod common;
+
+use common::*;
+use pdf_shape::*;
+use std::str::FromStr;
+
+fn sample(metadata: &str) -> String {
+    let block = block(
+        "p1_b1",
+        &[text(
+            "p1_t1",
+            &[token("p1_w1", 10.0, 10.0, 40.0, 12.0, "word")],
+        )],
+    );
+
+    document(&format!("<METADATA>{}</METADATA>\n{}", metadata, block))
+}
+
+#[test]
+fn partial_metadata_is_kept() {
+    let xml =
+        sample(r#"<PDFFILENAME>partial.pdf</PDFFILENAME><PROCESS name="pdftoxml"></PROCESS>"#);
+    let document = Document::from_str(&xml).unwrap();
+
+    assert_eq!(document.get_fsm_tokens().tokens.len(), 1);
+
+    let metadata = document.get_metadata().unwrap();
+
+    assert_eq!(metadata.pdf_filename(), "partial.pdf");
+    assert_eq!(metadata.tool_name(), "pdftoxml");
+    assert_eq!(metadata.tool_version(), "");
+    assert!(metadata.flags().is_empty());
+    assert_eq!(metadata.creation_date(), None);
+
+    // Missing elements are not written back
+    let written = document.to_xml().unwrap();
+
+    assert!(!written.contains("cmd="));
+    assert!(!written.contains("VERSION"));
+    assert!(!written.contains("CREATIONDATE"));
+    assert!(Document::from_str(&written).is_ok());
+}
+
+#[test]
+fn metadata_without_process_is_kept() {
+    let xml = sample(r#"<PDFFILENAME>partial.pdf</PDFFILENAME>"#);
+    let metadata = Document::from_str(&xml)
+        .unwrap()
+        .get_metadata()
+        .cloned()
+        .unwrap();
+
+    assert_eq!(metadata.pdf_filename(), "partial.pdf");
+    assert_eq!(metadata.tool_name(), "");
+
+    let xml = sample(r#"<PROCESS name="pdftoxml" cmd="-blocks"><VERSION/></PROCESS>"#);
+    let metadata = Document::from_str(&xml)
+        .unwrap()
+        .get_metadata()
+        .cloned()
+        .unwrap();
+
+    assert_eq!(metadata.pdf_filename(), "");
+    assert_eq!(metadata.tool_version(), "");
+    assert!(metadata.has_flag("-blocks"));
+}
+
+#[test]
+fn partial_metadata_is_streamed() {
+    let xml = sample(r#"<PROCESS name="pdftoxml" cmd="-blocks"></PROCESS>"#);
+    let mut reader = PageReader::new(xml.as_bytes());
+
+    assert_eq!(reader.next().unwrap().unwrap().number(), 1);
+    assert_eq!(reader.metadata().unwrap().flags(), vec!["-blocks"]);
+}
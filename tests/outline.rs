@@ -0,0 +1,105 @@
+use pdf_shape::*;
+
+const SAMPLE: &str = "./examples/xml_sample/sample_1.xml";
+const OUTLINE_SAMPLE: &str = "./examples/outline_sample/sample_1_outline.xml";
+
+fn document() -> Document {
+    let mut document = Document::from_path(SAMPLE).unwrap();
+    document.load_outline_path(OUTLINE_SAMPLE).unwrap();
+    document
+}
+
+fn titles(outline: &Outline, nodes: &[indextree::NodeId]) -> Vec<String> {
+    nodes
+        .iter()
+        .map(|node| outline.get(*node).unwrap().title().to_string())
+        .collect()
+}
+
+#[test]
+fn outline_entries_are_read() {
+    let document = document();
+    let outline = document.get_outline().unwrap();
+
+    let entries = outline
+        .entries()
+        .into_iter()
+        .map(|(depth, entry)| (depth, entry.title(), entry.page(), entry.top()))
+        .collect::<Vec<(usize, &str, Option<usize>, Option<f32>)>>();
+
+    assert_eq!(
+        entries,
+        vec![
+            (0, "Jury", Some(1), Some(450.0)),
+            (1, "Directrice de recherche", Some(1), Some(500.0)),
+            (1, "Rapporteurs", Some(1), Some(560.0)),
+            (2, "HDR", Some(1), Some(620.0)),
+            (1, "Suffragants", Some(1), Some(660.0)),
+            (1, "Université d'Aix-Marseille", Some(1), Some(725.0)),
+            (0, "Annexes", Some(2), Some(70.0)),
+            (0, "Sans lien", None, None),
+        ]
+    );
+}
+
+#[test]
+fn outline_hierarchy_is_kept() {
+    let document = document();
+    let outline = document.get_outline().unwrap();
+
+    let roots = outline.roots();
+    assert_eq!(titles(outline, roots), vec!["Jury", "Annexes", "Sans lien"]);
+
+    // A list following an item holds the children of the item
+    let jury = roots[0];
+    let sections = outline.children(jury);
+    assert_eq!(
+        titles(outline, &sections),
+        vec![
+            "Directrice de recherche",
+            "Rapporteurs",
+            "Suffragants",
+            "Université d'Aix-Marseille"
+        ]
+    );
+
+    // A list found in an item holds the children of the item
+    let rapporteurs = sections[1];
+    let children = outline.children(rapporteurs);
+    assert_eq!(titles(outline, &children), vec!["HDR"]);
+
+    assert_eq!(outline.parent(children[0]), Some(rapporteurs));
+    assert_eq!(outline.parent(rapporteurs), Some(jury));
+    assert_eq!(outline.parent(jury), None);
+    assert!(outline.children(roots[1]).is_empty());
+}
+
+#[test]
+fn outline_entries_are_matched_with_headings() {
+    let document = document();
+    let outline = document.get_outline().unwrap();
+
+    let token_ids = outline
+        .entries()
+        .into_iter()
+        .map(|(_, entry)| entry.token_ids())
+        .collect::<Vec<Vec<&str>>>();
+
+    assert_eq!(
+        token_ids,
+        vec![
+            // Titles are matched regardless of their case
+            vec!["p1_w75"],
+            vec!["p1_w76", "p1_w77", "p1_w78"],
+            vec!["p1_w86"],
+            // `HDR` is found twice, the occurrence closest to the target is kept
+            vec!["p1_w103"],
+            vec!["p1_w107"],
+            // Punctuation is ignored
+            vec!["p1_w123", "p1_w124", "p1_w125"],
+            // Page 2 is not part of the document
+            vec![],
+            vec![],
+        ]
+    );
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use common::*;
+use pdf_shape::*;
+use std::str::FromStr;
+
+/// A block holding a text of two tokens
+fn paragraph(page: usize, block: usize, y: f32) -> Element {
+    let id = |kind: &str, number: usize| format!("p{}_{}{}", page, kind, number);
+
+    common::block(
+        &id("b", block),
+        &[text(
+            &id("t", block),
+            &[
+                token(&id("w", block * 2 - 1), 10.0, y, 40.0, 12.0, "first"),
+                token(&id("w", block * 2), 60.0, y, 40.0, 12.0, "second"),
+            ],
+        )],
+    )
+}
+
+fn sample() -> Document {
+    let first = page(1, &join(&[paragraph(1, 1, 10.0), paragraph(1, 2, 40.0)]));
+    let second = sized_page(2, 842.0, 595.0, &paragraph(2, 1, 10.0).to_string());
+
+    Document::from_str(&document(&join(&[first, second]))).unwrap()
+}
+
+#[test]
+fn pages_are_read_in_order() {
+    let document = sample();
+    let pages = document.get_pages_borrowed();
+
+    assert_eq!(
+        pages
+            .iter()
+            .map(|page| (page.id(), page.number(), page.width(), page.height()))
+            .collect::<Vec<(&str, usize, Option<f32>, Option<f32>)>>(),
+        vec![
+            ("p1", 1, Some(595.0), Some(842.0)),
+            ("p2", 2, Some(842.0), Some(595.0)),
+        ]
+    );
+
+    assert_eq!(document.get_page(2).map(Page::id), Some("p2"));
+    assert!(document.get_page(0).is_none());
+    assert!(document.get_page(3).is_none());
+}
+
+#[test]
+fn pages_only_hold_their_own_objects() {
+    let document = sample();
+
+    let first = document.get_page(1).unwrap();
+    let second = document.get_page(2).unwrap();
+
+    let block_ids = |page: &Page| {
+        page.get_blocks_borrowed()
+            .iter()
+            .map(|block| block.id().to_string())
+            .collect::<Vec<String>>()
+    };
+    let text_ids = |page: &Page| {
+        page.get_texts_borrowed()
+            .iter()
+            .map(|text| text.id().to_string())
+            .collect::<Vec<String>>()
+    };
+    let token_ids = |page: &Page| {
+        page.get_fsm_tokens()
+            .tokens
+            .iter()
+            .map(|token| token.id().to_string())
+            .collect::<Vec<String>>()
+    };
+
+    assert_eq!(block_ids(first), vec!["p1_b1", "p1_b2"]);
+    assert_eq!(text_ids(first), vec!["p1_t1", "p1_t2"]);
+    assert_eq!(token_ids(first), vec!["p1_w1", "p1_w2", "p1_w3", "p1_w4"]);
+    assert_eq!(first.get_blocks().len(), 2);
+    assert_eq!(first.get_texts().len(), 2);
+
+    assert_eq!(block_ids(second), vec!["p2_b1"]);
+    assert_eq!(text_ids(second), vec!["p2_t1"]);
+    assert_eq!(token_ids(second), vec!["p2_w1", "p2_w2"]);
+    assert_eq!(second.get_tokens().0.len(), 2);
+
+    // Documents hold the objects of all their pages
+    assert_eq!(document.get_blocks_borrowed().len(), 3);
+    assert_eq!(document.get_fsm_tokens().tokens.len(), 6);
+}
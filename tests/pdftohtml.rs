@@ -0,0 +1,67 @@
+use pdf_shape::*;
+
+const SAMPLE: &str = "./examples/pdftohtml_sample/sample_1.xml";
+
+#[test]
+fn pdftohtml_tokens_are_loaded() {
+    let document = Document::from_pdftohtml_path(SAMPLE).unwrap();
+    let tokens = document.get_fsm_tokens();
+
+    let values = tokens
+        .tokens
+        .iter()
+        .map(|token| token.value.clone().unwrap_or_default())
+        .collect::<Vec<String>>();
+
+    assert_eq!(values, vec!["Bold", "plain", "it", "code"]);
+
+    // Widths are estimated from the number of characters of each token
+    let positions = tokens
+        .tokens
+        .iter()
+        .map(|token| (token.x(), token.y(), token.width(), token.height()))
+        .collect::<Vec<(f32, f32, f32, f32)>>();
+
+    assert_eq!(
+        positions,
+        vec![
+            (50.0, 100.0, 40.0, 14.0),
+            (100.0, 100.0, 50.0, 14.0),
+            (160.0, 100.0, 20.0, 14.0),
+            (50.0, 140.0, 40.0, 12.0),
+        ]
+    );
+
+    let images = document.get_pages_borrowed()[0].get_images_borrowed();
+
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].href(), Some("sample_1-1_1.png"));
+}
+
+#[test]
+fn pdftohtml_styles_are_resolved() {
+    let document = Document::from_pdftohtml_path(SAMPLE).unwrap();
+    let tokens = document.get_fsm_tokens();
+
+    let styles = tokens
+        .tokens
+        .iter()
+        .map(|token| (token.bold(), token.italic()))
+        .collect::<Vec<(Option<bool>, Option<bool>)>>();
+
+    // The empty `<b/>` doesn't leak its weight to the following tokens
+    assert_eq!(
+        styles,
+        vec![
+            (Some(true), Some(false)),
+            (Some(false), Some(false)),
+            (Some(false), Some(true)),
+            (Some(true), Some(true)),
+        ]
+    );
+
+    let code = tokens.tokens[3];
+    assert_eq!(code.font_name(), Some("Courier-BoldOblique"));
+    assert_eq!(code.font_size(), Some(9.0));
+    assert_eq!(code.color(), Color::from_hex("#ff0000"));
+}
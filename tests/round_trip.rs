@@ -0,0 +1,201 @@
+mod common;
+
+use common::*;
+use pdf_shape::*;
+use std::fs;
+use std::str::FromStr;
+
+/// Describes the tree of a document with everything pdf2xml writes
+fn tree(document: &Document) -> Vec<String> {
+    let mut tree = Vec::new();
+
+    if let Some(metadata) = document.get_metadata() {
+        tree.push(format!(
+            "METADATA {} {} {} {:?} {:?}",
+            metadata.pdf_filename(),
+            metadata.tool_name(),
+            metadata.tool_version(),
+            metadata.flags(),
+            metadata.creation_date()
+        ));
+    }
+
+    for page in document.get_pages_borrowed() {
+        tree.push(format!(
+            "PAGE {} {} {:?} {:?}",
+            page.id(),
+            page.number(),
+            page.width(),
+            page.height()
+        ));
+
+        for block in page.get_blocks_borrowed() {
+            tree.push(format!(
+                "BLOCK {} {} {} {} {}",
+                block.id(),
+                block.x(),
+                block.y(),
+                block.width(),
+                block.height()
+            ));
+
+            for token in &block.get_fsm_tokens().tokens {
+                tree.push(format!(
+                    "TOKEN {:?} {} {:?} {:?} {:?} {:?} {:?} {:?} {:?} {:?} {:?} {} {} {} {} {} {:?}",
+                    token.sid(),
+                    token.id(),
+                    token.font_name(),
+                    token.monospace(),
+                    token.serif(),
+                    token.symbolic(),
+                    token.bold(),
+                    token.italic(),
+                    token.font_size(),
+                    token.font_color(),
+                    token.rotation(),
+                    token.x,
+                    token.y,
+                    token.base,
+                    token.width,
+                    token.height(),
+                    token.value
+                ));
+            }
+        }
+
+        for image in page.get_images_borrowed() {
+            tree.push(format!(
+                "IMAGE {} {:?} {} {} {} {} {:?}",
+                image.id(),
+                image.sid(),
+                image.x(),
+                image.y(),
+                image.width(),
+                image.height(),
+                image.href()
+            ));
+        }
+
+        for text in page.get_texts_borrowed() {
+            tree.push(format!(
+                "TEXT {} {} {} {} {}",
+                text.id(),
+                text.x(),
+                text.y(),
+                text.width(),
+                text.height()
+            ));
+        }
+    }
+
+    tree
+}
+
+#[test]
+fn samples_survive_a_round_trip() -> Result<(), Error> {
+    for entry in fs::read_dir("./examples/xml_sample")? {
+        let path = entry?.path();
+
+        let document = Document::from_path(&path)?;
+        let xml = document.to_xml()?;
+        let copy = Document::from_str(&xml)?;
+
+        assert_eq!(tree(&document), tree(&copy), "{}", path.display());
+        assert_eq!(xml, copy.to_xml()?, "{}", path.display());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn images_of_pages_without_geometry_survive_a_round_trip() -> Result<(), Error> {
+    let document = Document::from_path("./examples/xml_sample/sample_3.xml")?;
+    let copy = Document::from_str(&document.to_xml()?)?;
+
+    let images = copy
+        .get_pages_borrowed()
+        .iter()
+        .map(|page| {
+            (
+                page.number(),
+                page.get_images_borrowed()
+                    .iter()
+                    .map(|image| image.id().to_string())
+                    .collect::<Vec<String>>(),
+            )
+        })
+        .collect::<Vec<(usize, Vec<String>)>>();
+
+    assert_eq!(
+        images,
+        vec![
+            (1, vec![String::from("p1_i1")]),
+            (2, vec![String::from("p2_i1"), String::from("p2_i2")]),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn pages_without_geometry_survive_a_round_trip() -> Result<(), Error> {
+    // The ids of the objects don't tell their page
+    let word = |number: usize, y: f32| {
+        block(
+            &format!("block{}", number),
+            &[text(
+                &format!("text{}", number),
+                &[token(
+                    &format!("word{}", number),
+                    10.0,
+                    y,
+                    40.0,
+                    12.0,
+                    "word",
+                )],
+            )],
+        )
+    };
+
+    let xml = document(&join(&[
+        format!(r#"<PAGE id="first" number="1">{}</PAGE>"#, word(1, 10.0)),
+        format!(
+            r#"<PAGE id="second" number="2" width="595">{}{}</PAGE>"#,
+            word(2, 10.0),
+            image("image1", 10.0, 30.0, 100.0, 50.0)
+        ),
+    ]));
+
+    let document = Document::from_str(&xml)?;
+    let written = document.to_xml()?;
+    let copy = Document::from_str(&written)?;
+
+    assert_eq!(tree(&document), tree(&copy));
+    assert!(written.contains(r#"<PAGE id="first" number="1">"#));
+    assert!(written.contains(r#"<PAGE id="second" number="2" width="595">"#));
+
+    let pages = copy
+        .get_pages_borrowed()
+        .iter()
+        .map(|page| {
+            (
+                page.id().to_string(),
+                page.number(),
+                page.width(),
+                page.height(),
+                page.get_blocks_borrowed().len(),
+                page.get_images_borrowed().len(),
+            )
+        })
+        .collect::<Vec<(String, usize, Option<f32>, Option<f32>, usize, usize)>>();
+
+    assert_eq!(
+        pages,
+        vec![
+            ("first".to_string(), 1, None, None, 1, 0),
+            ("second".to_string(), 2, Some(595.0), None, 1, 1),
+        ]
+    );
+
+    Ok(())
+}
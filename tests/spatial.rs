@@ -0,0 +1,294 @@
+mod common;
+
+use common::*;
+use pdf_shape::*;
+use std::str::FromStr;
+
+const SAMPLES: [&str; 2] = [
+    "./examples/xml_sample/sample_1.xml",
+    "./examples/xml_sample/sample_2.xml",
+];
+
+/// Tokens outside the pages of the samples: a tall one on their left and a wide one above them
+fn outside() -> String {
+    document(
+        &block(
+            "p1_b1",
+            &[text(
+                "p1_t1",
+                &[
+                    token("p1_w1", -300.0, -200.0, 20.0, 3000.0, "tall"),
+                    token("p1_w2", -200.0, -300.0, 3000.0, 20.0, "wide"),
+                ],
+            )],
+        )
+        .to_string(),
+    )
+}
+
+/// Calls a check with the tokens of every page of the samples
+fn for_each_page<F: Fn(&[&Token])>(check: F) {
+    for path in SAMPLES.iter() {
+        let document = Document::from_path(path).unwrap();
+
+        for page in document.get_pages_borrowed() {
+            check(&page.get_fsm_tokens().tokens);
+        }
+    }
+}
+
+fn ids(tokens: &[&Token]) -> Vec<String> {
+    tokens.iter().map(|token| token.id().to_string()).collect()
+}
+
+/// Regions around, inside and across the tokens of a page
+fn regions(tokens: &[&Token]) -> Vec<BoundingBox> {
+    let mut regions = vec![
+        BoundingBox::new(-1000.0, -1000.0, 10.0, 10.0),
+        BoundingBox::new(-1000.0, -1000.0, 5000.0, 5000.0),
+        BoundingBox::new(0.0, 0.0, 0.0, 0.0),
+    ];
+
+    for token in tokens.iter().step_by(3) {
+        let bbox = token.bounding_box();
+
+        regions.push(bbox);
+        regions.push(BoundingBox::new(
+            bbox.x() - 50.0,
+            bbox.y() - 20.0,
+            100.0,
+            40.0,
+        ));
+        regions.push(BoundingBox::new(bbox.right(), bbox.bottom(), 0.0, 0.0));
+    }
+
+    regions
+}
+
+fn brute_force_query<'a>(tokens: &[&'a Token], region: &BoundingBox) -> Vec<&'a Token> {
+    tokens
+        .iter()
+        .copied()
+        .filter(|token| {
+            let bbox = token.bounding_box();
+
+            bbox.left() <= region.right()
+                && region.left() <= bbox.right()
+                && bbox.top() <= region.bottom()
+                && region.top() <= bbox.bottom()
+        })
+        .collect()
+}
+
+/// Distances of the `k` tokens closest to a token, the token itself excluded
+fn brute_force_nearest(tokens: &[&Token], token: &Token, k: usize) -> Vec<f32> {
+    let bbox = token.bounding_box();
+
+    let mut distances = tokens
+        .iter()
+        .filter(|other| !std::ptr::eq(**other, token))
+        .map(|other| other.bounding_box().distance(&bbox))
+        .collect::<Vec<f32>>();
+    distances.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    distances.truncate(k);
+
+    distances
+}
+
+/// Closest token facing a token in a direction, the first indexed token winning ties
+fn brute_force_nearest_in_direction<'a>(
+    tokens: &[&'a Token],
+    token: &Token,
+    direction: Direction,
+) -> Option<&'a Token> {
+    let bbox = token.bounding_box();
+
+    tokens
+        .iter()
+        .copied()
+        .filter(|other| !std::ptr::eq(*other, token))
+        .filter_map(|other| {
+            let other_bbox = other.bounding_box();
+
+            let facing_horizontally =
+                other_bbox.top() < bbox.bottom() && bbox.top() < other_bbox.bottom();
+            let facing_vertically =
+                other_bbox.left() < bbox.right() && bbox.left() < other_bbox.right();
+
+            let gap = match direction {
+                Direction::Left if facing_horizontally => bbox.left() - other_bbox.right(),
+                Direction::Right if facing_horizontally => other_bbox.left() - bbox.right(),
+                Direction::Above if facing_vertically => bbox.top() - other_bbox.bottom(),
+                Direction::Below if facing_vertically => other_bbox.top() - bbox.bottom(),
+                _ => return None,
+            };
+
+            if gap >= 0.0 {
+                Some((gap, other))
+            } else {
+                None
+            }
+        })
+        .fold(
+            None,
+            |nearest: Option<(f32, &'a Token)>, (gap, other)| match nearest {
+                Some((nearest_gap, _)) if nearest_gap <= gap => nearest,
+                _ => Some((gap, other)),
+            },
+        )
+        .map(|(_, other)| other)
+}
+
+#[test]
+fn queries_match_a_brute_force_search() {
+    for_each_page(|tokens| {
+        let index = SpatialIndex::new(tokens.iter().copied());
+
+        assert_eq!(index.len(), tokens.len());
+
+        for region in regions(tokens) {
+            assert_eq!(
+                ids(&index.query(&region)),
+                ids(&brute_force_query(tokens, &region)),
+                "{:?}",
+                region
+            );
+        }
+    });
+}
+
+#[test]
+fn nearest_objects_match_a_brute_force_search() {
+    for_each_page(|tokens| {
+        let index = SpatialIndex::new(tokens.iter().copied());
+
+        for token in tokens {
+            for k in [1, 3, tokens.len()].iter() {
+                let nearest = index.nearest(*token, *k);
+                let distances = nearest
+                    .iter()
+                    .map(|other| other.bounding_box().distance(&token.bounding_box()))
+                    .collect::<Vec<f32>>();
+
+                assert_eq!(
+                    distances,
+                    brute_force_nearest(tokens, token, *k),
+                    "{}",
+                    token.id()
+                );
+                assert!(!nearest.iter().any(|other| std::ptr::eq(*other, *token)));
+            }
+        }
+    });
+}
+
+#[test]
+fn nearest_objects_in_a_direction_match_a_brute_force_search() {
+    let directions = [
+        Direction::Left,
+        Direction::Right,
+        Direction::Above,
+        Direction::Below,
+    ];
+
+    for_each_page(|tokens| {
+        let index = SpatialIndex::new(tokens.iter().copied());
+
+        for token in tokens {
+            for direction in directions.iter() {
+                assert_eq!(
+                    index
+                        .nearest_in_direction(*token, *direction)
+                        .map(|other| other.id()),
+                    brute_force_nearest_in_direction(tokens, token, *direction)
+                        .map(|other| other.id()),
+                    "{} {:?}",
+                    token.id(),
+                    direction
+                );
+            }
+        }
+    });
+}
+
+#[test]
+fn empty_indexes_find_nothing() {
+    let document = Document::from_str(&outside()).unwrap();
+    let tokens = document.get_pages_borrowed()[0].get_fsm_tokens();
+    let token = tokens.tokens[0];
+
+    let index = SpatialIndex::new(Vec::<&Token>::new());
+
+    assert!(index.is_empty());
+    assert!(index.objects().is_empty());
+    assert!(index
+        .query(&BoundingBox::new(-1000.0, -1000.0, 5000.0, 5000.0))
+        .is_empty());
+    assert!(index.nearest(token, 3).is_empty());
+    assert!(index
+        .nearest_in_direction(token, Direction::Right)
+        .is_none());
+}
+
+#[test]
+fn objects_outside_the_grid_match_a_brute_force_search() {
+    let document = Document::from_str(&outside()).unwrap();
+    let outside = document.get_pages_borrowed()[0].get_fsm_tokens();
+    let directions = [
+        Direction::Left,
+        Direction::Right,
+        Direction::Above,
+        Direction::Below,
+    ];
+
+    for_each_page(|tokens| {
+        let index = SpatialIndex::new(tokens.iter().copied());
+
+        for token in outside.tokens.iter() {
+            let bbox = token.bounding_box();
+
+            assert_eq!(
+                ids(&index.query(&bbox)),
+                ids(&brute_force_query(tokens, &bbox)),
+                "{}",
+                token.id()
+            );
+
+            for k in [1, 5].iter() {
+                let distances = index
+                    .nearest(*token, *k)
+                    .iter()
+                    .map(|other| other.bounding_box().distance(&bbox))
+                    .collect::<Vec<f32>>();
+
+                assert_eq!(
+                    distances,
+                    brute_force_nearest(tokens, token, *k),
+                    "{}",
+                    token.id()
+                );
+            }
+
+            for direction in directions.iter() {
+                assert_eq!(
+                    index
+                        .nearest_in_direction(*token, *direction)
+                        .map(|other| other.id()),
+                    brute_force_nearest_in_direction(tokens, token, *direction)
+                        .map(|other| other.id()),
+                    "{} {:?}",
+                    token.id(),
+                    direction
+                );
+            }
+        }
+
+        // Both tokens face tokens of the page
+        assert!(index
+            .nearest_in_direction(outside.tokens[0], Direction::Right)
+            .is_some());
+        assert!(index
+            .nearest_in_direction(outside.tokens[1], Direction::Below)
+            .is_some());
+    });
+}
@@ -0,0 +1,198 @@
+mod common;
+
+use common::*;
+use pdf_shape::*;
+use std::fs;
+use std::str::FromStr;
+
+/// What a page holds, to compare the pages read in different ways
+#[derive(Debug, PartialEq)]
+struct Summary {
+    id: String,
+    number: usize,
+    width: Option<f32>,
+    height: Option<f32>,
+    blocks: Vec<String>,
+    tokens: Vec<String>,
+    images: Vec<String>,
+}
+
+fn summary(page: &Page) -> Summary {
+    Summary {
+        id: page.id().to_string(),
+        number: page.number(),
+        width: page.width(),
+        height: page.height(),
+        blocks: page
+            .get_blocks_borrowed()
+            .iter()
+            .map(|block| block.id().to_string())
+            .collect(),
+        tokens: page
+            .get_fsm_tokens()
+            .tokens
+            .iter()
+            .map(|token| token.id().to_string())
+            .collect(),
+        images: page
+            .get_images_borrowed()
+            .iter()
+            .map(|image| image.id().to_string())
+            .collect(),
+    }
+}
+
+fn read(xml: &str) -> Vec<Result<Page, Error>> {
+    PageReader::new(xml.as_bytes()).collect()
+}
+
+/// A block holding a single token
+fn word(page: usize, number: usize) -> Element {
+    let id = |kind: &str| format!("p{}_{}{}", page, kind, number);
+
+    block(
+        &id("b"),
+        &[text(
+            &id("t"),
+            &[token(
+                &id("w"),
+                10.0,
+                number as f32 * 20.0,
+                40.0,
+                12.0,
+                "word",
+            )],
+        )],
+    )
+}
+
+#[test]
+fn pages_are_read_one_at_a_time() {
+    let xml = document(&join(&[
+        page(1, &join(&[word(1, 1), word(1, 2)])),
+        sized_page(2, 842.0, 595.0, &word(2, 1).to_string()),
+        page(3, &image("p3_i1", 10.0, 10.0, 100.0, 50.0)),
+    ]));
+
+    let pages = read(&xml)
+        .into_iter()
+        .map(|page| summary(&page.unwrap()))
+        .collect::<Vec<Summary>>();
+
+    assert_eq!(
+        pages
+            .iter()
+            .map(|page| (page.id.as_str(), page.number, page.width, page.height))
+            .collect::<Vec<(&str, usize, Option<f32>, Option<f32>)>>(),
+        vec![
+            ("p1", 1, Some(595.0), Some(842.0)),
+            ("p2", 2, Some(842.0), Some(595.0)),
+            ("p3", 3, Some(595.0), Some(842.0)),
+        ]
+    );
+    assert_eq!(pages[0].tokens, vec!["p1_w1", "p1_w2"]);
+    assert_eq!(pages[1].tokens, vec!["p2_w1"]);
+    assert!(pages[2].tokens.is_empty());
+    assert_eq!(pages[2].images, vec!["p3_i1"]);
+}
+
+#[test]
+fn blocks_outside_pages_are_split_by_page_id() {
+    let xml = document(&join(&[
+        word(1, 1).to_string(),
+        word(1, 2).to_string(),
+        image("p1_i1", 10.0, 60.0, 100.0, 50.0),
+        word(2, 1).to_string(),
+        word(3, 1).to_string(),
+    ]));
+
+    let pages = read(&xml)
+        .into_iter()
+        .map(|page| summary(&page.unwrap()))
+        .collect::<Vec<Summary>>();
+
+    assert_eq!(
+        pages
+            .iter()
+            .map(|page| (page.number, page.blocks.clone()))
+            .collect::<Vec<(usize, Vec<String>)>>(),
+        vec![
+            (1, vec!["p1_b1".to_string(), "p1_b2".to_string()]),
+            (2, vec!["p2_b1".to_string()]),
+            (3, vec!["p3_b1".to_string()]),
+        ]
+    );
+    assert_eq!(pages[0].images, vec!["p1_i1"]);
+    assert_eq!(pages[0].width, None);
+
+    let document = Document::from_str(&xml).unwrap();
+
+    assert_eq!(
+        pages,
+        document
+            .get_pages_borrowed()
+            .into_iter()
+            .map(summary)
+            .collect::<Vec<Summary>>()
+    );
+}
+
+#[test]
+fn truncated_documents_are_reported() {
+    let xml = document(&join(&[
+        page(1, &word(1, 1).to_string()),
+        page(2, &word(2, 1).to_string()),
+    ]));
+    let truncated = &xml[..xml.find("p2_w1").unwrap()];
+
+    let pages = read(truncated);
+
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[0].as_ref().map(Page::number).ok(), Some(1));
+    assert!(matches!(pages[1], Err(Error::Xml(_))));
+}
+
+#[test]
+fn invalid_pages_are_reported() {
+    let xml = document(&join(&[
+        page(1, &word(1, 1).to_string()),
+        page(2, &word(2, 1).to_string()).replace(r#" base=""#, r#" removed=""#),
+        page(3, &word(3, 1).to_string()),
+    ]));
+
+    let pages = read(&xml);
+
+    // Reading stops at the first error
+    assert_eq!(pages.len(), 2);
+    assert!(pages[0].is_ok());
+    assert!(pages[1].is_err());
+
+    let malformed = xml.replace("</TOKEN>", "</TEXT>");
+
+    assert!(read(&malformed).iter().any(|page| page.is_err()));
+}
+
+#[test]
+fn pages_are_the_pages_of_the_document() {
+    for entry in fs::read_dir("./examples/xml_sample").unwrap() {
+        let path = entry.unwrap().path();
+        let xml = fs::read_to_string(&path).unwrap();
+
+        let document = Document::from_str(&xml).unwrap();
+        let pages = PageReader::from_path(&path)
+            .unwrap()
+            .map(|page| summary(&page.unwrap()))
+            .collect::<Vec<Summary>>();
+
+        assert_eq!(
+            pages,
+            document
+                .get_pages_borrowed()
+                .into_iter()
+                .map(summary)
+                .collect::<Vec<Summary>>(),
+            "{}",
+            path.display()
+        );
+    }
+}
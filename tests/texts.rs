@@ -0,0 +1,113 @@
+mod common;
+
+use common::*;
+use pdf_shape::*;
+use std::str::FromStr;
+
+const SAMPLE: &str = "./examples/noblocks_sample/sample_1.xml";
+
+/// The id and the position of a block
+type Position = (String, f32, f32, f32, f32);
+
+/// The positions of the blocks of each page
+fn blocks(pages: &[&Page]) -> Vec<Vec<Position>> {
+    pages
+        .iter()
+        .map(|page| {
+            page.get_blocks_borrowed()
+                .iter()
+                .map(|block| {
+                    (
+                        block.id().to_string(),
+                        block.x(),
+                        block.y(),
+                        block.width(),
+                        block.height(),
+                    )
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn line(id: &str, y: f32) -> Element {
+    text(
+        id,
+        &[token(&format!("{}_w", id), 10.0, y, 40.0, 12.0, "word")],
+    )
+}
+
+#[test]
+fn texts_of_files_without_blocks_are_wrapped_into_blocks() {
+    let document = Document::from_path(SAMPLE).unwrap();
+    let expected = vec![
+        vec![
+            ("p1_b1".to_string(), 282.3, 454.8, 31.052, 16.408),
+            ("p1_b2".to_string(), 70.825, 504.32, 123.348, 14.064),
+        ],
+        vec![("p2_b1".to_string(), 344.5, 104.32, 58.276, 14.064)],
+    ];
+
+    assert_eq!(blocks(&document.get_pages_borrowed()), expected);
+    assert_eq!(document.get_fsm_tokens().tokens.len(), 5);
+    // Each block holds a single text
+    assert_eq!(document.get_texts_borrowed().len(), 3);
+
+    let pages = PageReader::from_path(SAMPLE)
+        .unwrap()
+        .collect::<Result<Vec<Page>, Error>>()
+        .unwrap();
+
+    assert_eq!(blocks(&pages.iter().collect::<Vec<&Page>>()), expected);
+}
+
+#[test]
+fn texts_outside_pages_are_wrapped_into_blocks() {
+    let xml = document(&join(&[
+        line("p1_t1", 10.0),
+        line("p1_t2", 30.0),
+        line("p2_t1", 10.0),
+    ]));
+
+    let expected = vec![
+        vec![
+            ("p1_b1".to_string(), 10.0, 10.0, 40.0, 12.0),
+            ("p1_b2".to_string(), 10.0, 30.0, 40.0, 12.0),
+        ],
+        vec![("p2_b1".to_string(), 10.0, 10.0, 40.0, 12.0)],
+    ];
+
+    let document = Document::from_str(&xml).unwrap();
+
+    assert_eq!(blocks(&document.get_pages_borrowed()), expected);
+
+    let pages = PageReader::new(xml.as_bytes())
+        .collect::<Result<Vec<Page>, Error>>()
+        .unwrap();
+
+    assert_eq!(blocks(&pages.iter().collect::<Vec<&Page>>()), expected);
+}
+
+#[test]
+fn blocks_of_texts_are_named_after_them() {
+    let xml = document(&page(
+        1,
+        &join(&[
+            line("p1_t13", 10.0),
+            line("p1_title_t2", 30.0),
+            line("caption", 50.0),
+        ]),
+    ));
+
+    let document = Document::from_str(&xml).unwrap();
+
+    assert_eq!(
+        document
+            .get_blocks_borrowed()
+            .iter()
+            .map(|block| block.id())
+            .collect::<Vec<&str>>(),
+        // Only the last `_t` is replaced, ids without any `_t` are kept
+        vec!["p1_b13", "p1_title_b2", "caption"]
+    );
+}
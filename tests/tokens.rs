@@ -0,0 +1,83 @@
+mod common;
+
+use common::*;
+use pdf_shape::*;
+use std::str::FromStr;
+
+/// A text of two tokens, the attributes of the second one being added by `flags`
+fn sample(flags: &str) -> String {
+    let second = token("p1_w2", 60.0, 10.0, 40.0, 12.0, "second")
+        .to_string()
+        .replace("<TOKEN ", &format!("<TOKEN {} ", flags));
+
+    document(&format!(
+        r#"<BLOCK id="p1_b1" x="10" y="10" width="90" height="12">
+<TEXT id="p1_t1" x="10" y="10" width="90" height="12">
+{}
+{}
+</TEXT>
+</BLOCK>"#,
+        token("p1_w1", 10.0, 10.0, 40.0, 12.0, "first"),
+        second
+    ))
+}
+
+#[test]
+fn missing_font_flags_are_unknown() {
+    let document = Document::from_str(&sample(r#"fixed-width="yes" serif="no""#)).unwrap();
+    let tokens = document.get_fsm_tokens();
+
+    assert_eq!(tokens.tokens[0].monospace(), None);
+    assert_eq!(tokens.tokens[0].serif(), None);
+    assert_eq!(tokens.tokens[0].symbolic(), None);
+
+    assert_eq!(tokens.tokens[1].monospace(), Some(true));
+    assert_eq!(tokens.tokens[1].serif(), Some(false));
+    assert_eq!(tokens.tokens[1].symbolic(), None);
+
+    // Sets holding a token whose flag is unknown don't know the flag either
+    assert_eq!(tokens.monospace(), None);
+    assert_eq!(tokens.serif(), None);
+
+    // Known flags are written back
+    let written = Document::from_str(&document.to_xml().unwrap()).unwrap();
+    let tokens = written.get_fsm_tokens();
+
+    assert_eq!(tokens.tokens[0].monospace(), None);
+    assert_eq!(tokens.tokens[1].monospace(), Some(true));
+    assert_eq!(tokens.tokens[1].serif(), Some(false));
+}
+
+#[test]
+fn font_colors_are_written_the_same_way() {
+    let xml = sample("").replace(r##"font-color="#000000""##, r##"font-color="#1F497D""##);
+    let document = Document::from_str(&xml).unwrap();
+    let tokens = document.get_fsm_tokens();
+
+    assert_eq!(tokens.tokens[0].font_color().as_deref(), Some("#1f497d"));
+    assert_eq!(tokens.font_color(), tokens.tokens[0].font_color());
+    assert_eq!(tokens.tokens[0].color(), Some(Color::new(0x1f, 0x49, 0x7d)));
+
+    // Tokens whose color can't be parsed have no font color
+    let xml = sample("").replace(r##"font-color="#000000""##, r##"font-color="blue""##);
+    let document = Document::from_str(&xml).unwrap();
+
+    assert_eq!(document.get_fsm_tokens().tokens[0].font_color(), None);
+}
+
+#[test]
+fn widest_tokens_are_found() {
+    let document = Document::from_str(&sample("")).unwrap();
+
+    // The last of the widest tokens is returned
+    assert_eq!(
+        document.get_fsm_tokens().widest_token().map(Token::id),
+        Some("p1_w2")
+    );
+
+    // Widths which aren't numbers don't make the comparison fail
+    let xml = sample("").replacen(r#"width="40""#, r#"width="NaN""#, 1);
+    let document = Document::from_str(&xml).unwrap();
+
+    assert!(document.get_fsm_tokens().widest_token().is_some());
+}
@@ -0,0 +1,156 @@
+use pdf_shape::*;
+
+const SAMPLE: &str = "./examples/xml_sample/sample_1.xml";
+const VEC_SAMPLE: &str = "./examples/vec_sample/sample_1.vec";
+
+fn document() -> Document {
+    let mut document = Document::from_path(SAMPLE).unwrap();
+    document.load_vec_path(VEC_SAMPLE).unwrap();
+    document
+}
+
+fn path<'a>(page: &'a Page, id: &str) -> &'a Path {
+    page.get_paths_borrowed()
+        .into_iter()
+        .find(|path| path.id() == Some(id))
+        .unwrap_or_else(|| panic!("missing path {}", id))
+}
+
+fn ends(line: &LineSegment) -> ((f32, f32), (f32, f32)) {
+    (line.start(), line.end())
+}
+
+#[test]
+fn paths_are_attached_to_their_pages() {
+    let document = document();
+    let pages = document.get_pages_borrowed();
+
+    // Pages missing from the document are inserted in page order, without geometry
+    let numbers = pages
+        .iter()
+        .map(|page| page.number())
+        .collect::<Vec<usize>>();
+    assert_eq!(numbers, vec![1, 2, 3]);
+
+    let ids = pages
+        .iter()
+        .map(|page| {
+            page.get_paths_borrowed()
+                .into_iter()
+                .map(|path| path.id().unwrap_or_default())
+                .collect::<Vec<&str>>()
+        })
+        .collect::<Vec<Vec<&str>>>();
+
+    // The group wrapping p1_s6 draws nothing and is not kept
+    assert_eq!(
+        ids,
+        vec![
+            vec!["p1_s1", "p1_s2", "p1_s3", "p1_s4", "p1_s6"],
+            vec!["p2_s1"],
+            vec!["p3_s1"],
+        ]
+    );
+
+    assert!(!pages[0].get_fsm_tokens().tokens.is_empty());
+    assert!(pages[1].get_fsm_tokens().tokens.is_empty());
+}
+
+#[test]
+fn styles_are_parsed_and_inherited() {
+    let document = document();
+    let page = document.get_pages_borrowed()[0];
+
+    let frame = path(page, "p1_s1");
+    assert_eq!(frame.stroke(), Color::from_hex("#000000"));
+    assert_eq!(frame.stroke_width(), Some(0.5));
+    assert_eq!(frame.fill(), None);
+    assert!(frame.closed());
+
+    let nested = path(page, "p1_s6");
+    assert_eq!(nested.stroke(), Color::from_hex("#0000ff"));
+    assert_eq!(nested.stroke_width(), Some(2.0));
+}
+
+#[test]
+fn rectangles_are_derived_from_paths() {
+    let document = document();
+    let page = document.get_pages_borrowed()[0];
+
+    // Closed path of three lines
+    let frame = path(page, "p1_s1").as_rect().unwrap();
+    assert_eq!(
+        (frame.x(), frame.y(), frame.width(), frame.height()),
+        (280.0, 452.0, 36.0, 20.0)
+    );
+    assert_eq!(frame.stroke(), Color::from_hex("#000000"));
+
+    // Path of four lines going back to its starting point
+    let background = path(page, "p1_s3").as_rect().unwrap();
+    assert_eq!(
+        (
+            background.x(),
+            background.y(),
+            background.width(),
+            background.height()
+        ),
+        (70.0, 500.0, 130.0, 20.0)
+    );
+    assert_eq!(background.fill(), Color::from_hex("#cccccc"));
+
+    assert!(path(page, "p1_s2").as_rect().is_none());
+    assert!(path(page, "p1_s4").as_rect().is_none());
+    assert!(path(page, "p1_s6").as_rect().is_none());
+
+    assert_eq!(page.get_rects().len(), 2);
+
+    // The frame surrounds the `JURY` heading
+    let heading = page
+        .get_fsm_tokens()
+        .tokens
+        .into_iter()
+        .find(|token| token.value.as_deref() == Some("JURY"))
+        .unwrap();
+    assert!(frame.bounding_box().contains(&heading.bounding_box()));
+}
+
+#[test]
+fn line_segments_are_derived_from_paths() {
+    let document = document();
+    let page = document.get_pages_borrowed()[0];
+
+    // Closed paths are joined back to their starting point
+    let frame = path(page, "p1_s1").line_segments();
+    assert_eq!(
+        frame.iter().map(ends).collect::<Vec<_>>(),
+        vec![
+            ((280.0, 452.0), (316.0, 452.0)),
+            ((316.0, 452.0), (316.0, 472.0)),
+            ((316.0, 472.0), (280.0, 472.0)),
+            ((280.0, 472.0), (280.0, 452.0)),
+        ]
+    );
+
+    let underline = path(page, "p1_s2").line_segments();
+    assert_eq!(underline.len(), 1);
+    assert!(underline[0].is_horizontal());
+    assert!(!underline[0].is_vertical());
+    assert!((underline[0].length() - 31.052).abs() < 0.001);
+    assert_eq!(underline[0].stroke(), Color::from_hex("#ff0000"));
+    assert_eq!(underline[0].stroke_width(), Some(1.0));
+
+    // Curves are skipped
+    let curve = path(page, "p1_s4").line_segments();
+    assert_eq!(
+        curve.iter().map(ends).collect::<Vec<_>>(),
+        vec![((40.0, 10.0), (40.0, 20.0))]
+    );
+    assert!(curve[0].is_vertical());
+
+    let slanted = path(page, "p1_s6").line_segments();
+    assert_eq!(slanted.len(), 1);
+    assert!(!slanted[0].is_horizontal() && !slanted[0].is_vertical());
+
+    // 4 + 1 + 4 + 1 + 1 lines
+    assert_eq!(page.get_line_segments().len(), 11);
+}